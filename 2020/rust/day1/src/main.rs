@@ -1,10 +1,10 @@
 use std::{env, fs, io};
 
-fn calc_expenses(expenses: &Vec<u32>, n: usize) {
+fn calc_expenses(expenses: &[u32], n: usize) {
     let input_len = expenses.len();
     let mut set: Vec<usize> = vec![0; n];
-    for i in 1..n {
-        set[i] = i * (input_len / n);
+    for (i, start) in set.iter_mut().enumerate().skip(1) {
+        *start = i * (input_len / n);
     }
 
     let split_index = expenses.len() / 2;
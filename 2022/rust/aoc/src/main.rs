@@ -0,0 +1,28 @@
+fn main() -> std::process::ExitCode {
+    aoc::main(&aoc::Workspace {
+        year: 2022,
+        root: concat!(env!("CARGO_MANIFEST_DIR"), "/.."),
+        solutions: &[
+            day1::SOLUTION,
+            day2::SOLUTION,
+            day3::SOLUTION,
+            day4::SOLUTION,
+            day5::SOLUTION,
+            day6::SOLUTION,
+            day7::SOLUTION,
+            day8::SOLUTION,
+            day9::SOLUTION,
+            day10::SOLUTION,
+            day11::SOLUTION,
+            day12::SOLUTION,
+            day13::SOLUTION,
+            day14::SOLUTION,
+            day15::SOLUTION,
+            day17::SOLUTION,
+            day20::SOLUTION,
+            day21::SOLUTION,
+            day22::SOLUTION,
+            day24::SOLUTION,
+        ],
+    })
+}
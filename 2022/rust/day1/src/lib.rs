@@ -0,0 +1,62 @@
+use aoc::{parse, ParseError};
+
+/// The calories of the food carried by every elf.
+fn decode_input(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|group| group.lines().map(|l| parse::number(input, l)).collect())
+        .collect()
+}
+
+fn totals(elves: &[Vec<usize>]) -> Vec<usize> {
+    elves.iter().map(|calories| calories.iter().sum()).collect()
+}
+
+fn part1(elves: &[Vec<usize>]) -> usize {
+    totals(elves).into_iter().max().unwrap_or(0)
+}
+
+fn part2(elves: &[Vec<usize>]) -> usize {
+    let mut totals = totals(elves);
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    totals.iter().take(3).sum()
+}
+
+struct Day1;
+
+impl aoc::Puzzle for Day1 {
+    type Input<'a> = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day1>(2022, 1, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::decode_input;
+
+    #[test]
+    fn test_decode_input() {
+        assert_eq!(
+            decode_input("1\n2\n\n3\n").unwrap(),
+            vec![vec![1, 2], vec![3]]
+        );
+        assert_eq!(
+            decode_input("1\n\nx\n").unwrap_err().to_string(),
+            "line 3, column 1: expected a number, found `x`"
+        );
+    }
+
+    aoc::example_tests!(super::Day1, 1, 2);
+}
@@ -0,0 +1,222 @@
+use aoc::{parse, ParseError};
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Addx(i32),
+    Noop,
+}
+fn decode_input(input: &str) -> Result<Vec<(i32, Operation)>, ParseError> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| match l.split_once(' ') {
+            Some(("addx", count)) => Ok((2, Operation::Addx(parse::number(input, count)?))),
+            None if l == "noop" => Ok((1, Operation::Noop)),
+            _ => Err(ParseError::at(input, l, "`addx <count>` or `noop`")),
+        })
+        .collect()
+}
+
+fn part1(input: &[(i32, Operation)]) -> i32 {
+    let mut cycle = 0;
+    let mut register_x = 1;
+    let mut program = input.iter();
+    let mut instruction = program.next().unwrap().clone();
+    let mut sum_x = 0;
+    loop {
+        cycle += 1;
+        if [20_i32, 60, 100, 140, 180, 220].contains(&cycle) {
+            sum_x += cycle * register_x;
+        }
+        instruction.0 -= 1;
+        debug_assert!(instruction.0 >= 0);
+        if instruction.0 == 0 {
+            if let Operation::Addx(operand) = instruction.1 {
+                register_x += operand;
+            }
+            if let Some(next_instr) = program.next() {
+                instruction = next_instr.clone();
+            } else {
+                break;
+            }
+        }
+    }
+    sum_x
+}
+
+struct Day10;
+
+impl aoc::Puzzle for Day10 {
+    type Input<'a> = Vec<(i32, Operation)>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day10>(2022, 10, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(13140, part1(&decode_input(TEST).unwrap()));
+    }
+
+    const TEST: &str = r#"
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+"#;
+
+    aoc::example_tests!(super::Day10, 1);
+}
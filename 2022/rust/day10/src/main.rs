@@ -1,351 +1,3 @@
-#[derive(Clone, Debug)]
-enum Operation {
-    Addx(i32),
-    Noop,
+fn main() -> std::process::ExitCode {
+    aoc::run_day(&day10::SOLUTION)
 }
-fn decode_input(input: &str) -> Vec<(i32, Operation)> {
-    input
-        .split_terminator('\n')
-        .filter(|l| !l.is_empty())
-        .map(|l| {
-            l.split_whitespace()
-                .fold((0, Operation::Noop), |_acc, s| match s {
-                    "addx" => (0, Operation::Addx(0)),
-                    "noop" => (1, Operation::Noop),
-                    count => (2, Operation::Addx(count.parse::<i32>().unwrap())),
-                })
-        })
-        .collect::<Vec<_>>()
-}
-
-fn part1(input: &[(i32, Operation)]) -> i32 {
-    let mut cycle = 0;
-    let mut register_x = 1;
-    let mut program = input.iter();
-    let mut instruction = program.next().unwrap().clone();
-    let mut sum_x = 0;
-    loop {
-        cycle += 1;
-        if [20_i32, 60, 100, 140, 180, 220].contains(&cycle) {
-            dbg!(register_x);
-            sum_x += cycle * register_x;
-        }
-        instruction.0 -= 1;
-        debug_assert!(instruction.0 >= 0);
-        if instruction.0 == 0 {
-            if let Operation::Addx(operand) = instruction.1 {
-                register_x += operand;
-            }
-            if let Some(next_instr) = program.next() {
-                instruction = next_instr.clone();
-            } else {
-                break;
-            }
-        }
-    }
-    sum_x
-}
-
-fn main() {
-    println!("{}", part1(&decode_input(INPUT)));
-}
-
-#[cfg(test)]
-mod test {
-    use crate::{decode_input, part1};
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(13140, part1(&decode_input(TEST)));
-    }
-
-    const TEST: &str = r#"
-addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop
-"#;
-}
-
-const INPUT: &str = r#"
-addx 1
-noop
-addx 2
-noop
-addx 3
-addx 3
-addx 1
-addx 5
-addx 1
-noop
-noop
-addx 4
-noop
-noop
-addx -9
-addx 16
-addx -1
-noop
-addx 5
-addx -2
-addx 4
-addx -35
-addx 2
-addx 28
-noop
-addx -23
-addx 3
-addx -2
-addx 2
-addx 5
-addx -8
-addx 19
-addx -8
-addx 2
-addx 5
-addx 5
-addx -14
-addx 12
-addx 2
-addx 5
-addx 2
-addx -13
-addx -23
-noop
-addx 1
-addx 5
-addx -1
-addx 2
-addx 4
-addx -9
-addx 10
-noop
-addx 6
-addx -11
-addx 12
-addx 5
-addx -25
-addx 30
-addx -2
-addx 2
-addx -5
-addx 12
-addx -37
-noop
-noop
-noop
-addx 24
-addx -17
-noop
-addx 33
-addx -32
-addx 3
-addx 1
-noop
-addx 6
-addx -13
-addx 17
-noop
-noop
-noop
-addx 12
-addx -4
-addx -2
-addx 2
-addx 3
-addx 4
-addx -35
-addx -2
-noop
-addx 20
-addx -13
-addx -2
-addx 5
-addx 2
-addx 23
-addx -18
-addx -2
-addx 17
-addx -10
-addx 17
-noop
-addx -12
-addx 3
-addx -2
-addx 2
-noop
-addx 3
-addx 2
-noop
-addx -13
-addx -20
-noop
-addx 1
-addx 2
-addx 5
-addx 2
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx 1
-addx 2
-addx -18
-noop
-addx 26
-addx -1
-addx 6
-noop
-noop
-noop
-addx 4
-addx 1
-noop
-noop
-noop
-noop:q
-"#;
@@ -0,0 +1,251 @@
+use aoc::{
+    bail,
+    combinator::{
+        blocks, delimited, field, integer, literal, pair, parse_all, preceded, separated, spaces,
+        PResult, Parser,
+    },
+    ensure,
+    num::lcm_all,
+    ParseError,
+};
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Value(usize),
+    Variable,
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(Operand, Operand),
+    Mul(Operand, Operand),
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: Vec<usize>,
+    operation: Operation,
+    test: (usize, usize, usize),
+}
+
+fn operand<'a>() -> impl Parser<'a, Operand> {
+    "old"
+        .map(|_| Operand::Variable)
+        .or(integer().map(Operand::Value))
+}
+
+/// A `<key>: <value>` line of a monkey's notes.
+fn note<'a, T>(key: &'a str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    delimited(pair("\n", spaces()), field(key, ": ", value), spaces())
+}
+
+/// `throw to monkey <n>` with the text of `n`, which can only be checked once
+/// all monkeys are known.
+fn target(input: &str) -> PResult<'_, (usize, &str)> {
+    let (_, rest) = literal("throw to monkey ").parse(input)?;
+    let (monkey, after) = integer().parse(rest)?;
+    Ok(((monkey, &rest[..rest.len() - after.len()]), after))
+}
+
+fn monkey(input: &str) -> PResult<'_, (Monkey, [&str; 2])> {
+    let (_, input) = delimited("Monkey ", integer::<usize>(), ":").parse(input)?;
+    let (items, input) = note("Starting items", separated(integer(), ", ")).parse(input)?;
+    let operator = "+".or("*");
+    let expression = pair(operand(), pair(delimited(" ", operator, " "), operand()));
+    let (operation, input) = note(
+        "Operation",
+        preceded("new = ", expression).map(|(a, (op, b))| match op {
+            "+" => Operation::Add(a, b),
+            _ => Operation::Mul(a, b),
+        }),
+    )
+    .parse(input)?;
+    let (divisor, input) = note("Test", preceded("divisible by ", integer())).parse(input)?;
+    let ((if_true, true_text), input) = note("If true", target).parse(input)?;
+    let ((if_false, false_text), input) = note("If false", target).parse(input)?;
+    let monkey = Monkey {
+        items,
+        operation,
+        test: (divisor, if_true, if_false),
+    };
+    Ok(((monkey, [true_text, false_text]), input))
+}
+
+fn decode_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = parse_all(blocks(monkey), input)?;
+    let count = monkeys.len();
+    for (i, (monkey, [true_text, false_text])) in monkeys.iter().enumerate() {
+        for (target, text) in [(monkey.test.1, true_text), (monkey.test.2, false_text)] {
+            if target >= count || target == i {
+                let expected = format!("another monkey below {count}");
+                return Err(ParseError::at(input, text, expected));
+            }
+        }
+    }
+    Ok(monkeys.into_iter().map(|(monkey, _)| monkey).collect())
+}
+
+fn apply_operation_to_worry_level(operation: &Operation, worry_level: &usize) -> usize {
+    match operation {
+        Operation::Add(Operand::Value(v1), Operand::Value(v2)) => v1 + v2,
+        Operation::Add(Operand::Value(v), Operand::Variable) => v + worry_level,
+        Operation::Add(Operand::Variable, Operand::Value(v)) => worry_level + v,
+        Operation::Add(Operand::Variable, Operand::Variable) => worry_level + worry_level,
+        Operation::Mul(Operand::Value(v1), Operand::Value(v2)) => v1 * v2,
+        Operation::Mul(Operand::Value(v), Operand::Variable) => v * worry_level,
+        Operation::Mul(Operand::Variable, Operand::Value(v)) => worry_level * v,
+        Operation::Mul(Operand::Variable, Operand::Variable) => worry_level * worry_level,
+    }
+}
+
+fn run_inspection(
+    monkeys: &mut [Monkey],
+    rounds: usize,
+    adapt_wlevel: impl Fn(usize) -> usize,
+) -> usize {
+    let mut counts = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for m in 0..monkeys.len() {
+            counts[m] += monkeys[m].items.len();
+            while !monkeys[m].items.is_empty() {
+                let mut worry_level = monkeys[m].items.remove(0);
+                worry_level = adapt_wlevel(apply_operation_to_worry_level(
+                    &monkeys[m].operation,
+                    &worry_level,
+                ));
+                let test = monkeys[m].test;
+                if worry_level.is_multiple_of(test.0) {
+                    monkeys[test.1].items.push(worry_level);
+                } else {
+                    monkeys[test.2].items.push(worry_level);
+                }
+            }
+        }
+    }
+    counts.sort();
+    counts[counts.len() - 1] * counts[counts.len() - 2]
+}
+
+fn part1(input: &mut [Monkey]) -> usize {
+    run_inspection(input, 20, |wl| wl / 3)
+}
+
+fn part2(input: &mut [Monkey]) -> aoc::Result<usize> {
+    // the divisibility tests only depend on the worry level modulo the lcm
+    // of the divisors, the worry levels stay below it
+    let Some(lcm) = lcm_all(input.iter().map(|m| m.test.0)) else {
+        bail!("the least common multiple of the divisors overflows");
+    };
+    ensure!(
+        lcm.checked_mul(lcm).is_some(),
+        "worry levels up to {lcm} overflow when squared"
+    );
+    Ok(run_inspection(input, 10000, |wl| wl % lcm))
+}
+
+struct Day11;
+
+impl aoc::Puzzle for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(&mut input.clone()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        part2(&mut input.clone())
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day11>(2022, 11, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(10605, part1(&mut decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(2713310158, part2(&mut decode_input(TEST).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_part2_overflow() {
+        let input = TEST.replace("by 23", "by 4294967311");
+        let err = part2(&mut decode_input(&input).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "worry levels up to 18034567738889 overflow when squared"
+        );
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let input = TEST.replace("old + 6", "old - 6");
+        let err = decode_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 11, column 24: expected `+` or `*`, found `-`"
+        );
+    }
+
+    #[test]
+    fn test_decode_target_error() {
+        let input = TEST.replace(
+            "throw to monkey 3\n\nMonkey 1",
+            "throw to monkey 4\n\nMonkey 1",
+        );
+        let err = decode_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, column 31: expected another monkey below 4, found `4`"
+        );
+        let input = TEST.replace("If true: throw to monkey 2", "If true: throw to monkey 0");
+        let err = decode_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 30: expected another monkey below 4, found `0`"
+        );
+    }
+
+    const TEST: &str = r#"
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+
+"#;
+
+    aoc::example_tests!(super::Day11, 1, 2);
+}
@@ -0,0 +1,114 @@
+use aoc::{
+    image::{Image, Palette},
+    parse, ParseError,
+};
+use grid::{Grid, Pos};
+use search::{astar, Path};
+
+struct HeightMap {
+    cells: Grid<u8>,
+    start: Pos,
+    goal: Pos,
+}
+
+impl HeightMap {
+    fn new(cells: Grid<u8>) -> Option<Self> {
+        let start = cells.position(|c| *c == b'S')?;
+        let goal = cells.position(|c| *c == b'E')?;
+        Some(HeightMap { cells, start, goal })
+    }
+
+    fn neighbors(&self, node: &Pos) -> Vec<(Pos, usize)> {
+        let cur_grid_val = self.cells[*node];
+        self.cells
+            .neighbours4(*node)
+            .filter(|&pos| {
+                let new_grid_val = self.cells[pos];
+                (new_grid_val >= b'a' && new_grid_val <= cur_grid_val + 1)
+                    || ((cur_grid_val + 1 == b'z' || cur_grid_val == b'z') && new_grid_val == b'E')
+                    || (cur_grid_val == b'S' && new_grid_val == b'a')
+            })
+            .map(|pos| (pos, 1))
+            .collect()
+    }
+}
+
+fn decode_input(input: &str) -> Result<HeightMap, ParseError> {
+    if let Some(i) = input.find(|c: char| !matches!(c, 'a'..='z' | 'S' | 'E' | '\n')) {
+        let c = input[i..].chars().next().unwrap_or_default();
+        let height = &input[i..i + c.len_utf8()];
+        return Err(ParseError::at(input, height, "a height, `S` or `E`"));
+    }
+    HeightMap::new(parse::grid(input, input, None)?).ok_or_else(|| {
+        ParseError::at(
+            input,
+            &input[input.len()..],
+            "the start `S` and the goal `E`",
+        )
+    })
+}
+
+fn find_path_to_goal(input: &HeightMap) -> Option<Path<Pos, usize>> {
+    astar(
+        [input.start],
+        |n| input.neighbors(n),
+        |n| n.manhattan(input.goal),
+        |n| *n == input.goal,
+    )
+    .path
+}
+
+fn part1(input: &HeightMap) -> usize {
+    let res = find_path_to_goal(input).unwrap();
+    aoc::image::snapshot(|| {
+        let mut image = Image::from_grid(&input.cells, |&c| {
+            let height = match c {
+                b'S' => b'a',
+                b'E' => b'z',
+                c => c,
+            };
+            Palette::TERRAIN.scale(f64::from(height - b'a'), 25.0)
+        });
+        for &pos in &res.nodes {
+            image[pos] = [220, 0, 0];
+        }
+        image.scaled(4)
+    });
+    res.cost
+}
+
+struct Day12;
+
+impl aoc::Puzzle for Day12 {
+    type Input<'a> = HeightMap;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day12>(2022, 12, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(31, part1(&decode_input(TEST).unwrap()));
+    }
+
+    const TEST: &str = r#"
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+"#;
+
+    aoc::example_tests!(super::Day12, 1);
+}
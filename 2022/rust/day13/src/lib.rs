@@ -0,0 +1,248 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use aoc::{parse, ParseError};
+
+#[derive(Debug, PartialEq, Clone)]
+enum Element {
+    ListStart,
+    Value(i32),
+    ListEnd,
+}
+
+fn map_line(input: &str, line: &str) -> Result<Vec<Element>, ParseError> {
+    let mut number_start = None;
+    let mut elements = vec![];
+    for (pos, char) in line.char_indices() {
+        match char {
+            '[' => {
+                elements.push(Element::ListStart);
+            }
+            ']' | ',' => {
+                if let Some(start_pos) = number_start {
+                    elements.push(Element::Value(parse::number(input, &line[start_pos..pos])?));
+                    number_start = None;
+                }
+                if char == ']' {
+                    elements.push(Element::ListEnd);
+                }
+            }
+            '0'..='9' => {
+                if number_start.is_none() {
+                    number_start = Some(pos);
+                }
+            }
+            _ => {
+                let found = &line[pos..pos + char.len_utf8()];
+                return Err(ParseError::at(input, found, "`[`, `]`, `,` or a digit"));
+            }
+        }
+    }
+    Ok(elements)
+}
+
+fn decode_input(input: &str) -> Result<Vec<Vec<Element>>, ParseError> {
+    input
+        .split_terminator('\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| map_line(input, line))
+        .collect()
+}
+
+fn cmp_lists(mut left: Cow<[Element]>, mut right: Cow<[Element]>) -> Ordering {
+    let mut order = Ordering::Equal;
+    let mut li = 0;
+    let mut ri = 0;
+    loop {
+        let left_element = if li < left.len() {
+            Some(left[li].clone())
+        } else {
+            None
+        };
+        let right_element = if ri < right.len() {
+            Some(right[ri].clone())
+        } else {
+            None
+        };
+        match (left_element, right_element) {
+            (None, None) => {
+                break;
+            }
+            (Some(Element::ListStart), Some(Element::ListStart)) => {},
+            (Some(Element::ListEnd), Some(Element::ListEnd)) => {},
+            (Some(Element::Value(l_val)), Some(Element::Value(r_val))) => {
+                if l_val < r_val {
+                    order = Ordering::Less;
+                    break;
+               } else if l_val > r_val {
+                    order = Ordering::Greater;
+                    break;
+                }
+            }
+            (Some(Element::ListStart), Some(Element::Value(_))) => {
+                li += 1;
+                right.to_mut().insert(ri+1, Element::ListEnd);
+                continue;
+            }
+            (Some(Element::Value(_)), Some(Element::ListStart)) => {
+                ri += 1;
+                left.to_mut().insert(li+1, Element::ListEnd);
+                continue;
+            }
+            (Some(Element::ListEnd), Some(Element::ListStart)) |
+                // [[[],7,5,6,[]]
+                // [[[[4]]]]
+            (Some(Element::ListEnd), Some(Element::Value(_))) => {
+                order = Ordering::Less;
+                break;
+            }
+            (Some(Element::ListStart), Some(Element::ListEnd)) |
+            (Some(Element::Value(_)), Some(Element::ListEnd)) |
+            (Some(Element::Value(_)), None) => {
+                order = Ordering::Greater;
+                break;
+            }
+            (le, re) => {
+                dbg!(le);
+                dbg!(re);
+                unreachable!()
+            },
+        }
+        li += 1;
+        ri += 1;
+    }
+    order
+}
+
+#[allow(dead_code)]
+fn print_lists(input: &[Vec<Element>]) {
+    input.iter().for_each(|v| {
+        v.iter().for_each(|e| {
+            match e {
+                Element::ListStart => print!("["),
+                Element::ListEnd => print!("],"),
+                Element::Value(val) => print!("{val},"),
+            };
+        });
+        println!();
+    });
+}
+
+fn part1(input: &[Vec<Element>]) -> usize {
+    let mut left = 0;
+    let mut right = 1;
+    let mut current_pair = 1;
+    let mut pair_ok_count = 0;
+    while right < input.len() {
+        if cmp_lists(Cow::from(&input[left]), Cow::from(&input[right])) == Ordering::Less {
+            pair_ok_count += current_pair;
+        }
+        left += 2;
+        right += 2;
+        current_pair += 1;
+    }
+    pair_ok_count
+}
+
+fn part2(input: &mut Vec<Vec<Element>>) -> usize {
+    let divider1 = vec![
+        Element::ListStart,
+        Element::ListStart,
+        Element::Value(2),
+        Element::ListEnd,
+        Element::ListEnd,
+    ];
+    let divider2 = vec![
+        Element::ListStart,
+        Element::ListStart,
+        Element::Value(6),
+        Element::ListEnd,
+        Element::ListEnd,
+    ];
+    input.push(divider1.clone());
+    input.push(divider2.clone());
+    input.sort_unstable_by(|l, r| cmp_lists(Cow::from(l), Cow::from(r)));
+    // print_lists(input);
+    input.iter().enumerate().fold(1, |acc, (i, l)| {
+        if l == &divider1 || l == &divider2 {
+            acc * (i + 1)
+        } else {
+            acc
+        }
+    })
+}
+
+struct Day13;
+
+impl aoc::Puzzle for Day13 {
+    type Input<'a> = Vec<Vec<Element>>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(&mut input.clone()))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day13>(2022, 13, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn test_part1() {
+        let input = super::decode_input(TEST).unwrap();
+        assert_eq!(13_usize, super::part1(&input));
+    }
+
+    #[test]
+    fn test_part1_special() {
+        let input = super::decode_input("\n[[8,[[7]]]]\n[[[[8]]]]").unwrap();
+        assert_eq!(0_usize, super::part1(&input));
+    }
+
+    #[test]
+    fn test_part1_special2() {
+        let input = super::decode_input("\n[[[[1],9],[[],0,3,5,4],[7,10,[]],2],[[[3],9,6,1],[],[[],[8,3,7,1]],7]]\n[[[9,3,[4,2]],4,6]]").unwrap();
+        assert_eq!(1_usize, super::part1(&input));
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut input = super::decode_input(TEST).unwrap();
+        assert_eq!(140_usize, super::part2(&mut input));
+    }
+
+    const TEST: &str = r#"
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+"#;
+
+    aoc::example_tests!(super::Day13, 1);
+}
@@ -0,0 +1,248 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc::{image::Image, parse, util::IterExt, ParseError};
+use grid::Pos;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Cave {
+    obstacles: HashSet<(usize, usize)>,
+    height: usize,
+    begin: usize,
+    end: usize,
+}
+
+impl Cave {
+    fn new() -> Self {
+        Self {
+            obstacles: HashSet::new(),
+            height: 0,
+            begin: usize::MAX,
+            end: 0,
+        }
+    }
+
+    fn next_position(&self, pos: (usize, usize)) -> (usize, usize) {
+        for (dx, dy) in [(0, 1), (-1, 1), (1, 1)] {
+            let Some(new_x) = pos.0.checked_add_signed(dx) else {
+                continue;
+            };
+            let new_y = pos.1 + dy;
+            if !self.obstacles.contains(&(new_x, new_y)) && new_y < self.height + 2 {
+                return (new_x, new_y);
+            }
+        }
+        pos
+    }
+
+    fn add_path_segment(&mut self, p1: (usize, usize), p2: (usize, usize)) {
+        assert!(p1.0.abs_diff(p2.0) == 0 || p1.1.abs_diff(p2.1) == 0);
+        let x1 = p1.0.min(p2.0);
+        let x2 = p1.0.max(p2.0);
+        (x1..=x2).for_each(|x| {
+            self.add_obstacle((x, p1.1));
+        });
+        let y1 = p1.1.min(p2.1);
+        let y2 = p1.1.max(p2.1);
+        (y1..=y2).for_each(|y| {
+            self.add_obstacle((p1.0, y));
+        });
+    }
+
+    /// The rocks and the `sand` resting on them, with the floor of part 2.
+    fn image(&self, sand: impl Fn(&(usize, usize)) -> bool) -> Image {
+        let mut image = Image::new(self.end - self.begin + 3, self.height + 3, [20, 20, 40]);
+        for obstacle in &self.obstacles {
+            let color = if sand(obstacle) {
+                [230, 190, 90]
+            } else {
+                [120, 110, 100]
+            };
+            image[Pos::new(obstacle.1, obstacle.0 + 1 - self.begin)] = color;
+        }
+        for col in 0..image.width() {
+            image[Pos::new(self.height + 2, col)] = [120, 110, 100];
+        }
+        image.scaled(4)
+    }
+
+    fn add_obstacle(&mut self, p: (usize, usize)) {
+        self.begin = self.begin.min(p.0);
+        self.end = self.end.max(p.0);
+        self.obstacles.insert(p);
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut list: Vec<(usize, usize)> = self.obstacles.iter().cloned().collect();
+        list.sort_by_key(|&(x, y)| (y, x));
+        for (_, row) in list.into_iter().group_by(|a| a.1) {
+            let mut cursor = self.begin;
+            for (value, _) in row {
+                write!(f, "{: >width$}", "o", width = value - cursor)?;
+                cursor = value;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "")
+    }
+}
+
+fn decode_input(input: &str) -> Result<Cave, ParseError> {
+    let mut cave = Cave::new();
+    for l in input.lines().filter(|line| !line.is_empty()) {
+        let mut p1: Option<(usize, usize)> = None;
+        for t in l.split(" -> ") {
+            let (x, y) = parse::split_once(input, t, ",")?;
+            let p2 = (parse::number(input, x)?, parse::number(input, y)?);
+            if let Some(p1) = p1 {
+                if p1.0 != p2.0 && p1.1 != p2.1 {
+                    return Err(ParseError::at(
+                        input,
+                        t,
+                        "a point in line with the previous one",
+                    ));
+                }
+                cave.add_path_segment(p1, p2);
+            }
+            cave.begin = p2.0.min(cave.begin);
+            cave.end = p2.0.max(cave.end);
+            cave.height = p2.1.max(cave.height);
+            p1 = Some(p2);
+        }
+    }
+    Ok(cave)
+}
+
+fn part1(mut cave: Cave, start: usize) -> usize {
+    let rocks = cave.obstacles.clone();
+    let mut pos = (start, 0);
+    let mut units = 0;
+    while pos.0 != cave.begin - 1 && pos.0 != cave.end + 1 {
+        pos = (start, 0);
+        let mut next_pos = cave.next_position(pos);
+        while pos != next_pos && pos.0 >= cave.begin && pos.0 <= cave.end && pos.1 < cave.height {
+            pos = next_pos;
+            next_pos = cave.next_position(pos);
+            if pos == next_pos {
+                cave.add_obstacle(pos);
+                units += 1;
+                aoc::record::frame(&cave);
+                break;
+            }
+        }
+    }
+    aoc::image::snapshot(|| cave.image(|p| !rocks.contains(p)));
+    units
+}
+
+fn part2(mut cave: Cave, start: usize) -> usize {
+    let rocks = cave.obstacles.clone();
+    let mut pos = (start, 0);
+    let mut next_pos = cave.next_position(pos);
+    let mut units = 1; // already counts the starting position
+    while pos != next_pos {
+        pos = next_pos;
+        next_pos = cave.next_position(pos);
+        if pos == next_pos {
+            cave.add_obstacle(pos);
+            units += 1;
+            // the floor fills up with tens of thousands of units
+            if units % 50 == 0 {
+                aoc::record::frame(&cave);
+            }
+            pos = (start, 0);
+            next_pos = cave.next_position(pos);
+        }
+    }
+    cave.add_obstacle((start, 0));
+    aoc::record::frame(&cave);
+    aoc::image::snapshot(|| cave.image(|p| !rocks.contains(p)));
+    units
+}
+
+struct Day14;
+
+impl aoc::Puzzle for Day14 {
+    type Input<'a> = Cave;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input.clone(), 500))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input.clone(), 500))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day14>(2022, 14, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::Cave;
+
+    #[test]
+    fn test_decode() {
+        let expected = Cave {
+            obstacles: HashSet::from([(498, 4), (498, 5), (498, 6), (497, 6), (496, 6)]),
+            height: 6,
+            begin: 496,
+            end: 498,
+        };
+        assert_eq!(
+            expected,
+            super::decode_input("498,4 -> 498,6 -> 496,6").unwrap()
+        );
+        let expected = Cave {
+            obstacles: HashSet::from([
+                (503, 4),
+                (502, 4),
+                (502, 5),
+                (502, 6),
+                (502, 7),
+                (502, 8),
+                (502, 9),
+                (501, 9),
+                (500, 9),
+                (499, 9),
+                (498, 9),
+                (497, 9),
+                (496, 9),
+                (495, 9),
+                (494, 9),
+            ]),
+            height: 9,
+            begin: 494,
+            end: 503,
+        };
+        assert_eq!(
+            expected,
+            super::decode_input("503,4 -> 502,4 -> 502,9 -> 494,9").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = super::decode_input(TEST).unwrap();
+        assert_eq!(24_usize, super::part1(input, 500));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = super::decode_input(TEST).unwrap();
+        assert_eq!(93_usize, super::part2(input, 500));
+    }
+
+    const TEST: &str = r#"
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+"#;
+
+    aoc::example_tests!(super::Day14, 1);
+}
@@ -0,0 +1,146 @@
+use aoc::{
+    combinator::{field, integer, lines, pair, parse_all, preceded, Parser},
+    eyre, ParseError,
+};
+use intervals::IntervalSet;
+use rayon::prelude::*;
+
+fn manhatten_distance(p1: (i32, i32), p2: (i32, i32)) -> i32 {
+    i32::abs(p1.0 - p2.0) + i32::abs(p1.1 - p2.1)
+}
+
+/// A position `x=<x>, y=<y>`.
+fn position<'a>() -> impl Parser<'a, (i32, i32)> {
+    pair(
+        field("x", "=", integer()),
+        preceded(", ", field("y", "=", integer())),
+    )
+}
+
+fn decode_input(input: &str) -> Result<Vec<[i32; 5]>, ParseError> {
+    let sensor = pair(
+        preceded("Sensor at ", position()),
+        preceded(": closest beacon is at ", position()),
+    );
+    parse_all(
+        lines(sensor.map(|(s, b)| [s.0, s.1, b.0, b.1, manhatten_distance(s, b)])),
+        input.trim_start(),
+    )
+}
+
+/// The x coordinates of `row` in reach of any sensor.
+fn coverage(row: i32, input: &[[i32; 5]]) -> IntervalSet<i32> {
+    input
+        .iter()
+        .filter_map(|s| {
+            let reach = s[4] - (s[1] - row).abs();
+            (reach >= 0).then(|| s[0] - reach..=s[0] + reach)
+        })
+        .collect()
+}
+
+fn part1(row: i32, input: &[[i32; 5]]) -> usize {
+    // The points of the row in the shadow of a sensor can't hold a beacon,
+    // except for the beacons that have been found already.
+    let mut covered = coverage(row, input);
+    input
+        .iter()
+        .filter(|s| s[3] == row)
+        .for_each(|s| covered.remove(s[2]..=s[2]));
+    covered.coverage() as usize
+}
+
+fn part2(input: &[[i32; 5]], min_xy: i32, max_xy: i32) -> aoc::Result<usize> {
+    // This time we search within a window of our field for the only
+    // coordinates that are not in reach of any sensor. Therefore the
+    // beacons themselves should not be ignored this time!
+    let window = IntervalSet::from(min_xy..=max_xy);
+    (min_xy..=max_xy)
+        .into_par_iter()
+        .find_map_first(|y| {
+            let x = window.difference(&coverage(y, input)).iter().next()?.start;
+            Some(x as usize * 4000000 + y as usize)
+        })
+        .ok_or_else(|| eyre!("every position from {min_xy} to {max_xy} is in reach of a sensor"))
+}
+
+/// The value of the option `name`, `default` if it isn't set.
+fn option(name: &str, default: i32) -> aoc::Result<i32> {
+    match aoc::options::get(name) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| eyre!("the option `{name}` has to be a number, got `{value}`")),
+        None => Ok(default),
+    }
+}
+
+struct Day15;
+
+impl aoc::Puzzle for Day15 {
+    type Input<'a> = Vec<[i32; 5]>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(option("row", 2000000)?, input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        part2(input, 0, option("size", 4000000)?)
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day15>(2022, 15, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2};
+
+    #[test]
+    fn test_part1_one_sensor() {
+        let input = decode_input("Sensor at x=8, y=7: closest beacon is at x=2, y=10").unwrap();
+        assert_eq!(12, part1(10, &input));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = decode_input(TEST).unwrap();
+        assert_eq!(26, part1(10, &input));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = decode_input(TEST).unwrap();
+        assert_eq!(56000011, part2(&input, 0, 20).unwrap());
+    }
+
+    #[test]
+    fn test_part2_covered() {
+        let input = decode_input("Sensor at x=2, y=2: closest beacon is at x=5, y=5").unwrap();
+        assert_eq!(
+            part2(&input, 0, 4).unwrap_err().to_string(),
+            "every position from 0 to 4 is in reach of a sensor"
+        );
+    }
+
+    const TEST: &str = r#"
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+"#;
+
+    aoc::example_tests!(super::Day15, 1, 2);
+}
@@ -0,0 +1,331 @@
+use std::{cmp::min, fmt::Display};
+
+use aoc::{
+    cycle::{Cycle, Detector},
+    ParseError,
+};
+
+#[derive(Debug)]
+enum Move {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+enum Shape {
+    Minus,
+    Plus,
+    Edge,
+    Bar,
+    Cube,
+}
+
+const SEQUENCE: [Shape; 5] = [
+    Shape::Minus,
+    Shape::Plus,
+    Shape::Edge,
+    Shape::Bar,
+    Shape::Cube,
+];
+
+const MINUS: [u8; 1] = [0b_0011_1100];
+const PLUS: [u8; 3] = [0b_0001_0000, 0b_0011_1000, 0b_0001_0000];
+const EDGE: [u8; 3] = [0b_0011_1000, 0b_0000_1000, 0b_0000_1000];
+const BAR: [u8; 4] = [0b_0010_0000, 0b_0010_0000, 0b_0010_0000, 0b_0010_0000];
+const CUBE: [u8; 2] = [0b_0011_0000, 0b_0011_0000];
+
+/// Rows below the top of the tower in a [`Chamber::snapshot`] until a rock
+/// falls deeper.
+const SURFACE_DEPTH: usize = 64;
+
+struct Chamber {
+    stack: Vec<u8>,
+    shape_count: usize,
+}
+
+impl Chamber {
+    fn new(capacity: usize) -> Self {
+        Self {
+            stack: Vec::with_capacity(capacity),
+            shape_count: 0,
+        }
+    }
+
+    fn rock_position_ok(&self, position: usize, shape: &[u8]) -> bool {
+        (position..min(self.stack.len(), position + shape.len()))
+            .enumerate()
+            .all(|(s, pos)| (shape[s] & self.stack[pos]) == 0)
+    }
+
+    fn apply_fall(&mut self, new_position: usize, shape: &[u8]) {
+        shape.iter().enumerate().for_each(|(i, s)| {
+            self.stack[new_position + i] |= *s;
+        });
+        self.shape_count += 1;
+    }
+
+    fn fill_height(&self) -> usize {
+        if let Some((fill, _)) = self
+            .stack
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, s)| *s & 0b_1111_1110 > 0)
+        {
+            fill + 1
+        } else {
+            0
+        }
+    }
+
+    /// What decides how the next rocks fall as long as none falls more than
+    /// `depth` rows: the next shape and jet and the free cells rocks can
+    /// still reach from above, as rows from the top of the tower down. Rocks
+    /// only move down and sideways, so the surface ends at the first row
+    /// without a reachable cell or after `depth` rows.
+    fn snapshot(&self, next_move: usize, depth: usize) -> (usize, usize, Vec<u8>) {
+        const COLUMNS: u8 = 0b_1111_1110;
+        let mut surface = vec![];
+        // the row above the tower is free
+        let mut reached = COLUMNS;
+        for row in self.stack[..self.fill_height()].iter().rev().take(depth) {
+            let free = !row & COLUMNS;
+            // rocks only move down and sideways
+            let mut cells = reached & free;
+            loop {
+                let spread = (cells | cells << 1 | cells >> 1) & free;
+                if spread == cells {
+                    break;
+                }
+                cells = spread;
+            }
+            if cells == 0 {
+                break;
+            }
+            surface.push(cells);
+            reached = cells;
+        }
+        (self.shape_count % SEQUENCE.len(), next_move, surface)
+    }
+
+    fn start_next_shape(&mut self) -> &[u8] {
+        (0..(4_usize - (self.stack.len() - self.fill_height()))).for_each(|_| {
+            self.stack.push(0b_0000_0001);
+        });
+        match SEQUENCE[self.shape_count % SEQUENCE.len()] {
+            Shape::Minus => &MINUS,
+            Shape::Plus => &PLUS,
+            Shape::Edge => &EDGE,
+            Shape::Bar => &BAR,
+            Shape::Cube => &CUBE,
+        }
+    }
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Top(self, self.stack.len()).fmt(f)
+    }
+}
+
+/// The upper rows of a [`Chamber`], the floor is only shown if it is among
+/// them.
+struct Top<'a>(&'a Chamber, usize);
+
+impl Display for Top<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Top(chamber, rows) = self;
+        for s in chamber.stack.iter().rev().take(*rows) {
+            writeln!(
+                f,
+                "|{}|",
+                (0..7)
+                    .map(|i| {
+                        if ((0b_1000_0000 >> i) & *s) > 0 {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            )?;
+        }
+        if *rows >= chamber.stack.len() {
+            writeln!(f, "+-------+")?;
+        }
+        Ok(())
+    }
+}
+
+fn decode_input(input: &str) -> Result<Vec<Move>, ParseError> {
+    let mut moves = Vec::with_capacity(input.len());
+    for l in input.split_terminator('\n').filter(|l| !l.is_empty()) {
+        for (i, c) in l.char_indices() {
+            moves.push(match c {
+                '<' => Move::Left,
+                '>' => Move::Right,
+                _ => {
+                    let found = &l[i..i + c.len_utf8()];
+                    return Err(ParseError::at(input, found, "`<` or `>`"));
+                }
+            });
+        }
+    }
+    Ok(moves)
+}
+
+fn shift_shape(mov: &Move, shape: &mut [u8]) {
+    match mov {
+        Move::Left => {
+            if shape.iter().all(|s| s & 0b_1000_0000 == 0) {
+                shape.iter_mut().for_each(|s| *s <<= 1)
+            }
+        }
+        Move::Right => {
+            if shape.iter().all(|s| s & 0b_0000_0010 == 0) {
+                shape.iter_mut().for_each(|s| *s >>= 1);
+            }
+        }
+    }
+}
+
+/// Drops rocks until `no_of_blocks` came to rest and returns the height of
+/// the tower. With `extrapolate` the height is extrapolated once the chamber
+/// repeats.
+///
+/// A snapshot only decides the rocks falling at most its depth, so the depth
+/// grows and the detector starts over whenever a rock falls deeper. Within a
+/// detected cycle every rock stayed inside the snapshots and falls the same
+/// way in every later period.
+fn simulate_blocks(moves: &[Move], no_of_blocks: usize, extrapolate: bool) -> usize {
+    let mut chamber = Chamber::new(moves.len());
+    let mut move_count = 0;
+    // heights[i] is the height after i rocks
+    let mut heights = vec![0];
+    let mut depth = SURFACE_DEPTH;
+    let mut detector = Detector::new();
+    // the number of rocks at the first snapshot in the detector
+    let mut origin = 0;
+    detector.push(chamber.snapshot(move_count % moves.len(), depth));
+    let mut shape = chamber.start_next_shape().to_vec();
+    let mut shape_pos = chamber.stack.len() - 1;
+    while chamber.shape_count < no_of_blocks {
+        // movement by jet stream
+        let orig_shape = shape.clone();
+        shift_shape(&moves[move_count % moves.len()], &mut shape);
+        move_count += 1;
+        if !chamber.rock_position_ok(shape_pos, &shape) {
+            shape = orig_shape;
+        }
+        // fall
+        if shape_pos > 0 && chamber.rock_position_ok(shape_pos - 1, &shape) {
+            shape_pos -= 1;
+            continue;
+        }
+        // the rows the rock passed, down to the one it rests on
+        let fall = chamber.fill_height() + 1 - shape_pos;
+        chamber.apply_fall(shape_pos, &shape);
+        aoc::record::frame(&Top(&chamber, 40));
+        heights.push(chamber.fill_height());
+        if extrapolate {
+            if fall > depth {
+                depth = 2 * fall;
+                detector = Detector::new();
+                origin = heights.len() - 1;
+            }
+            let snapshot = chamber.snapshot(move_count % moves.len(), depth);
+            if let Some(Cycle { start, length }) = detector.push(snapshot) {
+                let cycle = Cycle {
+                    start: origin + start,
+                    length,
+                };
+                return cycle.extrapolate(&heights, no_of_blocks);
+            }
+        }
+        shape = chamber.start_next_shape().to_vec();
+        shape_pos = chamber.stack.len() - 1;
+    }
+    chamber.fill_height()
+}
+
+fn part1(moves: &[Move]) -> usize {
+    simulate_blocks(moves, 2022, true)
+}
+
+fn part2(moves: &[Move]) -> usize {
+    simulate_blocks(moves, 1000000000000, true)
+}
+
+struct Day17;
+
+impl aoc::Puzzle for Day17 {
+    type Input<'a> = Vec<Move>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day17>(2022, 17, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2, simulate_blocks, Move};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(3068, part1(&decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(1514285714288, part2(&decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_extrapolation() {
+        // jet patterns of varying length from a linear congruential generator
+        let mut seed = 17_u32;
+        for len in [1, 2, 3, 7, 40, 333] {
+            let moves: Vec<Move> = (0..len)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    if seed >> 16 & 1 == 0 {
+                        Move::Left
+                    } else {
+                        Move::Right
+                    }
+                })
+                .collect();
+            for rocks in [1, 100, 2_022, 4_321] {
+                assert_eq!(
+                    simulate_blocks(&moves, rocks, true),
+                    simulate_blocks(&moves, rocks, false),
+                    "{len} jets, {rocks} rocks"
+                );
+            }
+        }
+        // a single jet leaves a shaft open down to the floor, the rocks pile
+        // up by 13 and 11 rows every 5 rocks
+        for (jet, height) in [(">", 2_600_000_000_000), ("<", 2_200_000_000_000)] {
+            let moves = decode_input(jet).unwrap();
+            assert_eq!(
+                simulate_blocks(&moves, 1_000_000_000_000, true),
+                height,
+                "{jet}"
+            );
+        }
+    }
+
+    const TEST: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    aoc::example_tests!(super::Day17, 1, 2);
+}
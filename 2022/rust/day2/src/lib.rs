@@ -0,0 +1,80 @@
+use aoc::{parse, ParseError};
+
+const ROCK: usize = 1;
+const PAPER: usize = 2;
+const SCISSORS: usize = 3;
+
+fn part1(input: &[usize]) -> usize {
+    input
+        .chunks(2)
+        .map(|t| match (t[0], t[1]) {
+            (SCISSORS, ROCK) => 6 + ROCK,
+            (PAPER, SCISSORS) => 6 + SCISSORS,
+            (ROCK, PAPER) => 6 + PAPER,
+            (g, i) if g == i => 3_usize + i,
+            (_, i) => i,
+        })
+        .sum::<usize>()
+}
+
+fn part2(input: &[usize]) -> usize {
+    const NEED_TO_LOSE: usize = 1; // "X"
+    const NEED_TO_DRAW: usize = 2; // "Y"
+    const NEED_TO_WIN: usize = 3; // "Z"
+    static LOSER_TABLE: [usize; 4] = [0_usize, SCISSORS, ROCK, PAPER];
+    static WINNER_TABLE: [usize; 4] = [0_usize, PAPER, SCISSORS, ROCK];
+    input
+        .chunks(2)
+        .map(|t| match t[1] {
+            NEED_TO_LOSE => LOSER_TABLE[t[0]],
+            NEED_TO_DRAW => t[0] + 3,
+            NEED_TO_WIN => WINNER_TABLE[t[0]] + 6,
+            _ => panic!("malformed input"),
+        })
+        .sum::<usize>()
+}
+
+fn decode_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut guide = vec![];
+    for line in input.lines() {
+        let (opponent, own) = parse::split_once(input, line, " ")?;
+        guide.push(match opponent {
+            "A" => ROCK,
+            "B" => PAPER,
+            "C" => SCISSORS,
+            _ => return Err(ParseError::at(input, opponent, "`A`, `B` or `C`")),
+        });
+        guide.push(match own {
+            "X" => 1,
+            "Y" => 2,
+            "Z" => 3,
+            _ => return Err(ParseError::at(input, own, "`X`, `Y` or `Z`")),
+        });
+    }
+    Ok(guide)
+}
+
+struct Day2;
+
+impl aoc::Puzzle for Day2 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day2>(2022, 2, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    aoc::example_tests!(super::Day2, 1, 2);
+}
@@ -1,2563 +1,3 @@
-const ROCK: usize = 1;
-const PAPER: usize = 2;
-const SCISSORS: usize = 3;
-
-fn part1(input: &[usize]) {
-    println!(
-        "part 1 {}",
-        input
-            .chunks(2)
-            .map(|t| {
-                match (t[0], t[1]) {
-                    (SCISSORS, ROCK) => 6 + ROCK,
-                    (PAPER, SCISSORS) => 6 + SCISSORS,
-                    (ROCK, PAPER) => 6 + PAPER,
-                    (g, i) if g == i => 3_usize + i,
-                    (_, i) => i,
-                }
-            })
-            .sum::<usize>()
-    );
+fn main() -> std::process::ExitCode {
+    aoc::run_day(&day2::SOLUTION)
 }
-
-fn part2(input: &[usize]) {
-    const NEED_TO_LOSE: usize = 1; // "X"
-    const NEED_TO_DRAW: usize = 2; // "Y"
-    const NEED_TO_WIN: usize = 3; // "Z"
-    static LOSER_TABLE: [usize; 4] = [0_usize, SCISSORS, ROCK, PAPER];
-    static WINNER_TABLE: [usize; 4] = [0_usize, PAPER, SCISSORS, ROCK];
-    println!(
-        "part 2 {}",
-        input
-            .chunks(2)
-            .map(|t| {
-                match t[1] {
-                    NEED_TO_LOSE => LOSER_TABLE[t[0]],
-                    NEED_TO_DRAW => t[0] + 3,
-                    NEED_TO_WIN => WINNER_TABLE[t[0]] + 6,
-                    _ => panic!("malformed input"),
-                }
-            })
-            .sum::<usize>()
-    );
-}
-
-fn main() {
-    let input = INPUT
-        .split_whitespace()
-        .map(|i| match i.as_bytes() {
-            b"A" => ROCK,
-            b"B" => PAPER,
-            b"C" => SCISSORS,
-            b"X" => 1,
-            b"Y" => 2,
-            b"Z" => 3,
-            _ => panic!("malformed input"),
-        })
-        .collect::<Vec<_>>();
-    part1(&input);
-    part2(&input);
-}
-
-static INPUT: &str = r#"
-C Z
-C Z
-A X
-A X
-B Z
-B Z
-B Z
-A Z
-B X
-A X
-A X
-A X
-C Z
-C Z
-C X
-A X
-A X
-A X
-C Z
-B Z
-C Z
-A Y
-B Z
-A X
-C Y
-A X
-A X
-C Y
-C Z
-A Y
-B Z
-A X
-C Y
-B Z
-B Z
-B Z
-A X
-C X
-C X
-B Z
-A X
-C Z
-A X
-B Z
-A Y
-C X
-A X
-C Z
-C Z
-B Z
-C Y
-C X
-C X
-C X
-C X
-A Y
-C Y
-C Z
-C Y
-A X
-C Y
-A X
-B Z
-A Y
-C X
-A X
-B Y
-A X
-C X
-C X
-C Z
-A X
-C X
-A X
-C X
-B Z
-A Z
-B Y
-B Z
-B Z
-A X
-C Y
-B X
-A X
-A X
-B Z
-A X
-C X
-C Z
-C Z
-A X
-C Z
-C Z
-C Z
-C X
-C Z
-A X
-B Z
-B Y
-C Z
-B Z
-B Z
-A X
-B Z
-C Y
-C Z
-A X
-A Y
-C Z
-A X
-B Y
-C Y
-C Z
-A X
-C Y
-C X
-A X
-C X
-A X
-C Z
-A X
-C Y
-A Y
-C Z
-C X
-C Y
-C Y
-A Y
-A Y
-C Y
-C Y
-A Y
-A X
-C Z
-C Z
-C X
-C X
-C Z
-A Y
-C Z
-C Z
-A Y
-A Y
-B Z
-A X
-B Z
-C Y
-B Z
-C Z
-C Y
-C Y
-B Y
-C X
-A X
-A X
-A X
-A Y
-A X
-C Z
-C Z
-C Z
-A X
-B Z
-A X
-B Z
-C Y
-C Z
-A Y
-C Y
-A X
-A X
-C Z
-B X
-C Z
-C Z
-B Z
-B Z
-B Z
-C Z
-C X
-C Z
-A X
-B Z
-C Z
-C X
-A X
-A Y
-B Y
-A X
-B Z
-A X
-C Z
-C Z
-C X
-B Z
-C Z
-C Z
-C X
-A X
-A X
-B Y
-C Y
-C Z
-B Y
-A X
-B Z
-A X
-B X
-B Z
-C X
-C X
-C Y
-C X
-B Y
-B Z
-C X
-A Y
-C X
-C Y
-A X
-C Z
-C Z
-C X
-B Z
-A Y
-B Z
-A Y
-B X
-A X
-C Z
-B Z
-C X
-C X
-C X
-A X
-C X
-B Z
-C Z
-B Y
-B Z
-A X
-A X
-C Z
-B Z
-A X
-A Z
-C Y
-B Z
-C X
-C Z
-A Y
-C X
-A X
-A X
-A Y
-B Z
-A X
-B Z
-A Y
-B X
-C X
-C Y
-A X
-C Z
-B X
-C Z
-C X
-C X
-C Z
-A X
-B Z
-C Z
-C X
-C Z
-B Y
-A X
-C X
-C Y
-A X
-C Z
-A X
-B Z
-C X
-C X
-B Z
-C Z
-C Z
-C Y
-A X
-C Z
-C X
-A X
-C Y
-C Z
-C Z
-C Y
-B Y
-B Z
-A X
-B Y
-C X
-A X
-A X
-C Y
-C Z
-B Z
-C X
-C X
-B Z
-C Z
-A X
-A Y
-A X
-C Y
-A Y
-B Y
-A X
-A X
-C X
-C X
-B Z
-A Y
-B Z
-C Z
-A X
-A Y
-A X
-C X
-A X
-C Z
-B Y
-C Z
-A X
-B Z
-C Z
-C Z
-A X
-A X
-B Z
-B Z
-B Z
-A X
-C X
-A X
-A X
-C Y
-C Y
-C X
-C X
-A X
-A Y
-B Y
-C Z
-C Z
-B Y
-C X
-C Z
-A Y
-C X
-C Z
-C X
-B Z
-C Y
-C Z
-C X
-C Y
-C Z
-B Z
-C Z
-B Z
-A X
-B Y
-B Y
-C Z
-B Z
-C X
-C Z
-C Z
-C Z
-B Y
-B Z
-C Z
-B Y
-A X
-A X
-C X
-A X
-C X
-A X
-B Z
-A X
-A X
-A X
-C Z
-A Y
-A X
-C Z
-C X
-A X
-C X
-A X
-B Z
-C Z
-C Z
-C Z
-A X
-A X
-C Z
-A X
-B Z
-C X
-C Z
-C Y
-B Z
-A X
-B Z
-A X
-A Z
-B Y
-B Z
-B Z
-B Z
-A Y
-C Z
-A X
-B Y
-C X
-B X
-B Z
-C X
-B Y
-C Z
-A X
-C X
-B Z
-A X
-B Z
-A X
-A X
-B Y
-C Y
-C X
-C X
-C Z
-A X
-C Z
-A X
-B Y
-C X
-C Y
-B Y
-B Y
-A Y
-C X
-A Y
-C Z
-C Y
-A X
-C Z
-C Z
-B Z
-A X
-A X
-A X
-A X
-B Z
-C Z
-C X
-C X
-C Z
-A X
-B Z
-B Y
-C Y
-A Y
-C X
-B Z
-A Y
-B Z
-C Z
-B Z
-C X
-A X
-B Z
-A X
-A Y
-C Y
-B Z
-B Z
-C X
-C Z
-C Z
-C Z
-A X
-B Z
-A Y
-A X
-C Y
-A Y
-B Z
-A Y
-C Z
-A Y
-A Y
-C X
-A X
-B Z
-A Y
-B Z
-C X
-A X
-B Y
-B Z
-C Z
-C Z
-B Z
-A X
-A X
-B Y
-C Z
-C Y
-B Z
-C Z
-A Y
-A X
-C X
-C Z
-A X
-A X
-A Z
-C X
-C X
-B X
-B Z
-A Y
-C X
-A Y
-C X
-C Z
-A Y
-B Z
-A X
-B X
-A Y
-C X
-C Y
-A Y
-C X
-B Y
-C Y
-A X
-B Z
-A X
-C Z
-A X
-A X
-C Z
-C Z
-B X
-C X
-A Y
-B Z
-C Z
-A X
-A X
-C Z
-C Y
-A X
-C X
-C Z
-C Y
-C X
-C Y
-C X
-C Z
-C Z
-B Z
-A X
-A Y
-B Z
-A X
-B Z
-C X
-B Y
-A X
-A X
-A X
-A X
-B Z
-C Z
-B Z
-B Y
-C Z
-C X
-C Z
-C X
-B Z
-C Z
-A X
-C Z
-C Z
-A Y
-C X
-A X
-B Z
-A X
-B Z
-B Y
-A X
-A X
-A X
-A Y
-C Z
-A X
-B Y
-A Y
-B Y
-C Y
-A X
-A X
-C Y
-A X
-C Z
-B Z
-C X
-C Z
-A X
-B Z
-C Z
-B Z
-B Z
-B Z
-C X
-C Z
-A X
-A Y
-C X
-C Z
-B Z
-B Y
-C Z
-B Y
-B Z
-C Z
-A Y
-B Z
-C X
-C Y
-A X
-B Y
-A X
-C Z
-C X
-C Z
-A X
-C X
-B Z
-B X
-C Z
-B Z
-A Y
-A X
-A Y
-C Z
-C Y
-A X
-B X
-A Z
-C Y
-C X
-C Y
-C X
-C Y
-B Z
-C X
-B Y
-C Z
-C Y
-B Z
-A Y
-B Y
-C Z
-C X
-B Z
-A Y
-C Z
-C Z
-C Z
-C Y
-C X
-C X
-B Y
-C Z
-A Y
-C Z
-C X
-B Y
-C X
-B Z
-B Z
-B Z
-B Z
-C Y
-C Z
-C X
-B X
-C Z
-C Z
-C Z
-C X
-B Y
-B Z
-C X
-A X
-C Y
-B X
-C X
-C X
-C Z
-A X
-A X
-B Z
-B Z
-A X
-C Z
-A X
-A Y
-C X
-B X
-C Y
-C Y
-A X
-A X
-C Z
-C Z
-B Y
-B Y
-B Z
-C X
-A X
-A Z
-C Z
-B Y
-C Y
-C X
-C Y
-B Z
-C X
-A X
-A X
-C Z
-C Y
-B X
-C Z
-B Z
-A X
-C X
-A Y
-C Y
-C Z
-B Z
-C Z
-A X
-C Y
-B Z
-C Y
-B X
-C Z
-C Z
-A X
-A X
-C X
-C Z
-C X
-C Y
-C Z
-A Y
-C X
-C Z
-B Y
-B Y
-C Z
-A X
-C Y
-A X
-C X
-C X
-B Y
-C Z
-C Z
-A X
-A Y
-C Z
-B Z
-C Y
-A X
-A X
-C Z
-C Z
-A X
-C Z
-C Z
-B Z
-B Z
-A X
-A Y
-A X
-C Z
-C X
-A X
-B X
-C Z
-C Z
-B Y
-A X
-B Z
-C X
-C Z
-C Z
-C Z
-C X
-A Y
-A X
-A Y
-A X
-A Y
-C Z
-C Z
-B Z
-B Y
-A X
-B Z
-C Z
-C X
-C Y
-C Z
-A X
-C Y
-B Y
-B Z
-C Z
-B Y
-C X
-C Z
-B Y
-B Z
-B Y
-C X
-C Z
-C X
-C Z
-B Y
-B Z
-C X
-A X
-A Y
-B Z
-B Y
-A X
-A X
-C Z
-A X
-A X
-C X
-A X
-C Z
-C X
-A X
-B X
-A Y
-C X
-A X
-C Y
-A X
-C Z
-A X
-A X
-C Z
-A X
-A X
-C Z
-A X
-B Y
-B Z
-A X
-C X
-A X
-C X
-B Z
-C Y
-A Y
-C Z
-B Z
-A X
-C X
-A X
-B Y
-C X
-B Y
-B Z
-A X
-B Z
-C X
-B Z
-A X
-A X
-C X
-A X
-C X
-A Y
-C Z
-A X
-B Y
-B Z
-C Z
-C Z
-A X
-C Y
-B Z
-C X
-B Z
-B Z
-C Z
-A X
-C X
-C X
-A Y
-A X
-C Z
-B Z
-A X
-B X
-A X
-B Z
-B Z
-C Z
-B Y
-C Z
-A X
-C Z
-C Z
-C Z
-C X
-A X
-A X
-C Y
-B Y
-C Z
-B Y
-A X
-B Z
-A Y
-C Y
-B Y
-C Z
-C X
-C X
-A X
-B Y
-A X
-A X
-C Z
-C X
-C Y
-A X
-A X
-A X
-C X
-B Z
-B Y
-A X
-B Y
-C X
-C Z
-B Z
-A X
-C X
-C Z
-B Y
-A X
-C Z
-B Z
-B Z
-A X
-B Y
-A X
-B Y
-C Z
-C Z
-B Z
-C Z
-C Y
-A X
-B Z
-C Z
-A Y
-C Z
-B Z
-B Z
-C Z
-B Y
-C Z
-C Z
-B Z
-A Y
-C X
-C Y
-B Z
-C X
-C Z
-A X
-A X
-C Y
-A Z
-C Z
-C X
-B Z
-B Z
-C Y
-A X
-A Y
-C Z
-A X
-A X
-B Z
-A X
-C X
-C X
-B X
-B X
-A Z
-B Y
-A X
-A Y
-C Z
-A X
-A X
-B Z
-B Z
-C Y
-A X
-C Y
-C Z
-A Y
-C Y
-B X
-C X
-A X
-B Z
-A X
-A X
-A X
-A X
-A X
-B X
-A X
-C Z
-B Z
-B Z
-B X
-A X
-C Z
-B Z
-C Z
-C Z
-C Y
-A X
-C Y
-C Y
-C Z
-A Y
-C Z
-A X
-C X
-C Z
-A X
-A Y
-C Z
-C X
-A X
-C X
-B Y
-C Z
-B Z
-A X
-C Y
-B Z
-B Y
-A X
-C Z
-A X
-B Z
-C Z
-C Y
-C Z
-A X
-A X
-B Z
-B Y
-A X
-C X
-C Y
-B X
-C Z
-A Y
-C X
-B Y
-B Z
-A Y
-C Y
-C Z
-C Z
-C X
-A X
-A X
-C X
-C X
-C Z
-C X
-C X
-B X
-B Z
-B Z
-C X
-C Z
-C X
-A X
-A X
-A X
-C Z
-C Y
-B Z
-C X
-C X
-A X
-C X
-C Y
-A X
-B Y
-A X
-C Z
-C Z
-B Z
-A X
-A X
-A X
-B Z
-C X
-A X
-B Y
-B Z
-C X
-B Y
-C X
-B Y
-C Z
-B X
-A X
-A X
-B Z
-A X
-A X
-A X
-B Z
-A X
-C Z
-C X
-C Z
-A X
-C Y
-C Y
-C Z
-C Z
-C Z
-A X
-C Z
-C X
-C Z
-C Z
-A X
-B Z
-C X
-C Y
-B X
-B Z
-B Z
-A X
-C Y
-B Y
-C Z
-B Z
-C Z
-C X
-A Z
-C Z
-B X
-C X
-C Z
-C Z
-C X
-C Z
-C Z
-A X
-B Z
-C Z
-C X
-A X
-B Z
-C Y
-C Y
-B Y
-C X
-A X
-A X
-C Z
-B Z
-C X
-C X
-C X
-B Y
-C Z
-B X
-C X
-C Z
-A X
-A X
-C Z
-C Z
-C X
-C X
-C Z
-A X
-C Z
-C X
-C Z
-C Z
-A X
-C Z
-A X
-A X
-A X
-B Z
-A X
-B Z
-C Z
-C Z
-A X
-C Z
-C Z
-B Z
-C Z
-A Y
-C X
-A X
-A X
-C X
-A X
-C X
-C X
-A Y
-B Y
-C Z
-B Z
-C X
-B Y
-C Y
-C X
-C Z
-A X
-C Y
-B Z
-B Y
-A X
-B Y
-B Y
-A X
-C X
-C X
-A X
-A Y
-C Y
-B Z
-A X
-B Z
-A Y
-B Y
-C Z
-C Z
-C Z
-A Y
-B Y
-B Y
-C Z
-B Z
-C Z
-A X
-C Y
-A X
-B Y
-B Z
-C X
-C Y
-A X
-B Z
-B Z
-A X
-B Z
-C Y
-A X
-C X
-C Z
-C Z
-A X
-C Y
-A X
-C X
-C X
-B Z
-B Y
-A X
-C Y
-B Z
-B Y
-A X
-C Z
-A X
-B Y
-A X
-A X
-A X
-C X
-A X
-C Z
-B Z
-C Z
-A X
-A X
-B Y
-C X
-C Y
-C Z
-A X
-C Z
-B Z
-A X
-B Z
-A X
-B Y
-B Z
-C Z
-C Z
-C Y
-C X
-A X
-C Z
-A Y
-A X
-B Z
-B Y
-A X
-C X
-C Z
-B Z
-A X
-C Z
-C X
-C Y
-A X
-A X
-C Z
-A X
-A X
-C X
-C X
-A X
-C X
-C Z
-A X
-A X
-C Y
-A Y
-B Z
-C X
-C X
-B Y
-B Z
-A X
-B Z
-C Z
-C Z
-A Y
-C Y
-C Z
-A X
-C Z
-A X
-C Y
-C X
-B X
-B Z
-A X
-A X
-B Z
-A X
-C X
-C X
-A Y
-A X
-A X
-C Y
-B Z
-A X
-B Y
-C X
-B Z
-C Y
-B Y
-A X
-C Z
-B Y
-B Z
-A X
-B Y
-C X
-C Z
-A X
-B Z
-A X
-C Y
-C Z
-A X
-A X
-C Y
-C Y
-C X
-A Y
-C Z
-B Z
-A X
-A Y
-C Z
-C Z
-C Z
-C Z
-B Y
-A Y
-A X
-A X
-C X
-A X
-B Y
-C Z
-B Z
-A Y
-C X
-C X
-A Y
-A X
-A X
-C X
-C Z
-C X
-A X
-A Y
-C X
-C Z
-C X
-A X
-A X
-C Z
-C X
-A X
-C X
-C Z
-A X
-A X
-C Z
-B Y
-A X
-C Z
-C Y
-C Z
-C Z
-B X
-B Z
-B X
-A X
-C Z
-B X
-C Z
-A X
-C Z
-A Y
-A Z
-B Z
-C X
-A X
-A X
-A X
-A X
-A X
-C Z
-A X
-B X
-A Y
-A X
-C X
-A X
-A Y
-A Y
-B Y
-C X
-A Y
-A X
-C Z
-C Z
-C Y
-B Z
-C X
-C Z
-A Y
-C X
-C Y
-C X
-A X
-B Y
-C Z
-C Z
-A X
-C Z
-B Z
-B Z
-C X
-C X
-A X
-B Y
-C X
-C X
-A X
-C Z
-B Y
-C Y
-C Z
-A X
-B Z
-C Z
-B Y
-C X
-A X
-B Y
-B Z
-A X
-C Y
-B Z
-C Z
-C X
-A X
-C X
-A X
-B Y
-C X
-A X
-C Y
-B Z
-B Z
-B Z
-C Y
-A X
-A X
-A Y
-C Z
-B X
-B Z
-A X
-A X
-C X
-A X
-C X
-A X
-C X
-A X
-A X
-A X
-A Y
-A Y
-B Y
-A X
-B Y
-A X
-B Y
-B Z
-B Y
-A Y
-C Z
-C Z
-C Z
-C Z
-A X
-C Z
-C X
-C Y
-C Z
-B Z
-B Z
-B Z
-C Y
-C Z
-B Z
-C X
-A Y
-A X
-C X
-C Y
-A Y
-B Y
-C X
-C X
-B Y
-C Z
-A X
-C X
-C X
-C X
-C X
-C X
-C X
-B Z
-C Y
-A X
-A X
-B Y
-B Z
-A X
-C X
-C Z
-A X
-B X
-B Z
-C Y
-B Z
-C Z
-C Y
-A X
-C Z
-B Y
-C X
-A X
-A Y
-A X
-C X
-A Y
-A Y
-C Z
-B Z
-C Z
-C X
-C Y
-B Y
-A X
-C X
-C Z
-B Y
-A X
-B X
-B Z
-C Z
-B Z
-B Z
-A X
-A Y
-A X
-A X
-B Z
-C Z
-A X
-A Y
-C Z
-C Z
-C Y
-A X
-B Z
-C X
-C X
-A Y
-A X
-C X
-C Y
-C Z
-C Y
-C X
-A X
-B Z
-C Y
-B Z
-C X
-A X
-A X
-C X
-C Z
-C Y
-C Y
-A X
-A Y
-C Z
-A Y
-B Y
-C Y
-A Y
-C Z
-B Y
-C X
-A X
-B Z
-C Z
-A X
-A X
-B Z
-C X
-B Z
-C Z
-C Z
-A Y
-A X
-C X
-A X
-B Z
-C X
-A X
-A X
-B Y
-A Z
-B Z
-C X
-C Z
-C X
-C Z
-C X
-A X
-C Z
-B Y
-C Y
-C Z
-A X
-C Z
-A X
-A Z
-A X
-A X
-C Z
-A X
-B Z
-C X
-B Z
-A X
-C X
-A X
-C X
-C Z
-B Z
-A Y
-C Y
-C Z
-C Z
-C X
-C Z
-A X
-A X
-B Y
-B Z
-C Y
-B Z
-A Y
-A X
-A X
-B Z
-B X
-A X
-B Y
-B Z
-C X
-A X
-A X
-C Z
-B Y
-C X
-B Y
-A Y
-A Z
-A X
-B Y
-C X
-C Z
-C Z
-C Y
-C Z
-A X
-B X
-C X
-A Y
-A X
-C Z
-C Y
-C X
-B Z
-A X
-C X
-C Z
-A X
-C Z
-A X
-C X
-C X
-A Y
-C Z
-A X
-C X
-C Z
-C Y
-A X
-A Y
-A Y
-C Z
-C X
-A X
-C Z
-A Y
-A Y
-A X
-B Z
-C Z
-B Y
-C Z
-B Y
-C X
-C Z
-B Y
-A X
-A X
-B Z
-A X
-C Z
-A X
-A X
-B Y
-A X
-B Z
-A Y
-A X
-A Y
-C Z
-B Z
-A X
-B X
-B Y
-B Z
-A X
-A Z
-B Y
-B Z
-C X
-B Z
-A X
-B Z
-C Z
-B Y
-C X
-A X
-A Y
-B Y
-A Y
-C X
-C X
-B Y
-A Y
-B Z
-C Z
-B Z
-A Y
-A X
-B Z
-B Z
-A Y
-C Z
-C Z
-B Z
-C X
-B Z
-B Y
-B Z
-B Z
-A X
-A X
-C X
-C Z
-B Z
-A X
-B Z
-C Y
-C Z
-A Z
-A X
-A X
-C Z
-A X
-C Z
-C Z
-C Z
-B Z
-A X
-A Y
-B X
-A X
-A X
-A X
-C Z
-B X
-B X
-A X
-C Z
-A X
-B X
-A X
-A X
-B Z
-A X
-C Z
-C Y
-A X
-B Z
-A X
-C Z
-B Z
-A X
-A X
-B Y
-B Y
-C Y
-A X
-B Z
-C Y
-A X
-C Z
-B Z
-A X
-C Z
-B Z
-C X
-B X
-B Z
-C Z
-A X
-B Z
-A X
-A X
-A X
-A X
-C Z
-B Z
-B Y
-C Z
-A X
-C Z
-A X
-C X
-C X
-A X
-B Y
-C X
-B Z
-A Y
-C Y
-B Z
-C Y
-C X
-A X
-B Z
-C X
-B Y
-A X
-A Y
-B Y
-B Y
-A X
-C X
-C Z
-B Z
-A Y
-A X
-B Y
-C X
-B Z
-B X
-C Y
-B X
-C X
-B Y
-C X
-A X
-C Z
-B Z
-B Y
-B Y
-A X
-A X
-A Z
-B Z
-B Z
-C X
-C Y
-A Y
-C Z
-C X
-A X
-C Z
-C X
-B Z
-A Y
-B Z
-C Z
-B Z
-B Z
-C X
-B Z
-C X
-C X
-B Z
-A X
-B Y
-B Z
-A X
-C X
-A X
-C Y
-A X
-C X
-B X
-C Z
-C Y
-C Z
-B Z
-C X
-A X
-A X
-A Y
-C Z
-C Y
-A Y
-C Z
-B Z
-C Z
-C Y
-A X
-A X
-C X
-C Z
-C Z
-A Z
-C Z
-A X
-B Z
-C X
-C Z
-A X
-B Z
-C X
-A X
-A Y
-B Y
-C X
-A X
-A X
-C X
-C Y
-B Z
-C X
-A X
-C X
-B Z
-B Y
-A Y
-C Z
-A X
-C Z
-C Z
-A X
-C Y
-B Z
-A Y
-B Y
-B Y
-A X
-A X
-C X
-C X
-B Y
-C Z
-C Z
-C Z
-C X
-C Y
-C Z
-B Z
-C Z
-A Y
-C Z
-A X
-B Z
-B Y
-B Z
-A X
-A X
-A X
-A X
-C X
-C Z
-B Z
-C X
-B Z
-C Z
-A X
-C X
-C Z
-B Y
-A X
-C X
-A X
-B Z
-B Z
-A X
-A X
-B Y
-C Y
-C Y
-C X
-A X
-B Z
-C Y
-B Z
-A Y
-B Y
-B Y
-A X
-B Z
-A X
-C X
-C Z
-A X
-B Z
-A Y
-C Y
-B Z
-C Z
-C Y
-A X
-C Y
-A X
-C X
-B Z
-C X
-A X
-C Z
-A X
-B Y
-B Z
-C X
-C Y
-B X
-A X
-B Z
-A X
-B Y
-C X
-C X
-C Y
-C Y
-C X
-B Y
-C Z
-C X
-B Z
-B Y
-C X
-A Y
-C Z
-C Z
-C X
-B X
-B Y
-A X
-A X
-C Z
-C X
-C Y
-A X
-C Y
-A Y
-C Z
-C X
-A X
-C Z
-C X
-A X
-B X
-C X
-C X
-C X
-B Y
-B Z
-C X
-C Z
-A Y
-B Y
-C X
-C X
-A X
-B Z
-C Z
-A X
-C Y
-C Z
-A Y
-B Z
-C X
-C Z
-A X
-C Z
-B Z
-B Y
-B Z
-A X
-C X
-A Y
-C X
-C Z
-B Y
-C Y
-C Z
-C Z
-A X
-A X
-A X
-C Z
-B Y
-C Z
-A X
-A X
-B Z
-B Y
-B Z
-C Z
-B Z
-A Y
-C Y
-C Z
-B Y
-A X
-A X
-C Z
-B Y
-C Z
-A X
-C X
-B Y
-A Y
-B Z
-A X
-A X
-A X
-B Y
-A X
-C Z
-B Y
-C Z
-B Y
-C Z
-C X
-C Y
-C X
-A X
-A X
-A X
-C Z
-C X
-C X
-B Y
-A Y
-B Z
-B X
-C Z
-B Y
-A X
-C Y
-B Z
-C X
-A X
-A X
-A X
-A X
-C X
-B Y
-A X
-A X
-B Y
-A X
-B Z
-C Z
-A X
-A X
-A X
-A X
-B Z
-A X
-C Z
-C X
-C Y
-B Z
-C Z
-A X
-C Y
-C Z
-A X
-A X
-C X
-B Z
-C X
-B Z
-C Z
-A X
-A X
-A Y
-B Y
-C Z
-B Y
-A X
-C X
-C Z
-C Z
-C X
-A X
-A X
-C Z
-A X
-A Y
-A X
-A X
-C Z
-C X
-C X
-C Z
-C X
-A X
-C Z
-C Z
-B Z
-A Y
-A Y
-B Y
-A X
-A X
-C X
-C X
-A X
-A X
-C X
-C X
-B Y
-C Z
-A X
-B Y
-A X
-A X
-A X
-C X
-C Y
-B Z
-B Y
-B Z
-C Z
-C Z
-C X
-C Z
-A X
-B Z
-A Y
-C Z
-B Z
-A X
-C Y
-B Z
-C Z
-C Y
-A X
-B Y
-C Z
-A X
-A X
-A X
-C Z
-C Z
-C X
-A Y
-C X
-B Z
-B Y
-A X
-C X
-C X
-A Y
-A Y
-A X
-B X
-B Z
-B Y
-B Y
-A X
-C Y
-A X
-C Z
-C X
-C X
-C Z
-B Z
-B Z
-C Y
-C X
-B Z
-C Z
-A Y
-C Y
-A X
-B Z
-A X
-C Y
-B Z
-B Y
-C Z
-A X
-A X
-C Z
-B Z
-B Z
-C Z
-C Y
-C Z
-C Z
-C X
-A Y
-A Y
-B Z
-C Z
-B Y
-C X
-C X
-A Z
-C Z
-A X
-A X
-C X
-A Y
-C X
-A X
-A X
-A X
-C Y
-A X
-B Y
-A X
-B Y
-A X
-A X
-A Y
-C X
-C Z
-A X
-C X
-B Y
-B Z
-B Z
-A Y
-C Z
-C X
-C X
-B Z
-B Z
-C X
-B Y
-A X
-B Y
-A X
-A X
-C Y
-B X
-C X
-A Z
-A Z
-A X
-C Z
-C Y
-C Z
-C Y
-"#;
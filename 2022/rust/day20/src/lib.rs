@@ -0,0 +1,112 @@
+use std::collections::LinkedList;
+
+use aoc::{parse, util::remove_at, ParseError};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+fn decode_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .split_terminator('\n')
+        .filter(|l| !l.is_empty())
+        .map(|l| parse::number(input, l))
+        .collect()
+}
+
+fn mix_list(input: &[i64], list: &mut LinkedList<usize>) {
+    input.iter().enumerate().for_each(|(index, code)| {
+        if *code != 0 {
+            let pos = list.iter().position(|v| *v == index).unwrap();
+            remove_at(list, pos);
+            let new_pos = if *code < 0 {
+                let new_pos = (pos as i64 + *code) % list.len() as i64;
+                if new_pos < 0 {
+                    (list.len() as i64 + new_pos) as usize
+                } else {
+                    new_pos as usize
+                }
+            } else {
+                ((pos as i64 + *code) % list.len() as i64) as usize
+            };
+            if new_pos == 0 {
+                list.push_back(index);
+            } else {
+                let mut split = list.split_off(new_pos);
+                split.push_front(index);
+                list.append(&mut split);
+            }
+        }
+    });
+}
+
+fn read_grove_coordinates(input: &[i64], list: &LinkedList<usize>) -> i64 {
+    let index = input.iter().position(|v| *v == 0).unwrap();
+    let pos = list.iter().position(|v| *v == index).unwrap();
+    input[*list.iter().nth((pos + 1000) % input.len()).unwrap()]
+        + input[*list.iter().nth((pos + 2000) % input.len()).unwrap()]
+        + input[*list.iter().nth((pos + 3000) % input.len()).unwrap()]
+}
+
+fn part1(input: &[i64]) -> i64 {
+    let mut list = LinkedList::from_iter(0_usize..input.len());
+    mix_list(input, &mut list);
+    read_grove_coordinates(input, &list)
+}
+
+fn part2(input: &[i64]) -> i64 {
+    let input = input
+        .iter()
+        .map(|n| *n * DECRYPTION_KEY)
+        .collect::<Vec<_>>();
+    let mut list = LinkedList::from_iter(0_usize..input.len());
+    for _ in 0..10 {
+        mix_list(&input, &mut list);
+    }
+    read_grove_coordinates(&input, &list)
+}
+
+struct Day20;
+
+impl aoc::Puzzle for Day20 {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day20>(2022, 20, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(3, part1(&decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(1623178306, part2(&decode_input(TEST).unwrap()));
+    }
+
+    const TEST: &str = r#"
+1
+2
+-3
+3
+-2
+0
+4
+"#;
+
+    aoc::example_tests!(super::Day20, 1);
+}
@@ -0,0 +1,668 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{bail, dot::Graph, ensure, eyre, num::Rational, parse, ParseError};
+
+#[derive(Debug)]
+enum Operation<'a> {
+    Add(&'a str, &'a str),
+    Sub(&'a str, &'a str),
+    Mul(&'a str, &'a str),
+    Div(&'a str, &'a str),
+    Val(i128),
+}
+
+impl<'a> Operation<'a> {
+    /// The monkeys waited for, `None` for a number.
+    fn operands(&self) -> Option<(&'a str, &'a str)> {
+        match *self {
+            Operation::Add(a, b)
+            | Operation::Sub(a, b)
+            | Operation::Mul(a, b)
+            | Operation::Div(a, b) => Some((a, b)),
+            Operation::Val(_) => None,
+        }
+    }
+
+    /// The operator or the number yelled.
+    fn symbol(&self) -> String {
+        match self {
+            Operation::Add(..) => "+".into(),
+            Operation::Sub(..) => "-".into(),
+            Operation::Mul(..) => "*".into(),
+            Operation::Div(..) => "/".into(),
+            Operation::Val(v) => v.to_string(),
+        }
+    }
+}
+
+type Monkey<'a> = (&'a str, Operation<'a>);
+type MonkeyMap<'a> = HashMap<&'a str, Monkey<'a>>;
+
+fn decode_input(input: &str) -> Result<MonkeyMap<'_>, ParseError> {
+    let mut hmap = HashMap::new();
+    let mut names = vec![];
+    for l in input.split_terminator('\n').filter(|l| !l.is_empty()) {
+        let (key, job) = parse::split_once(input, l, ": ")?;
+        let operation = match job.split(' ').collect::<Vec<_>>()[..] {
+            [value] => Operation::Val(parse::number(input, value)?),
+            [a, "+", b] => Operation::Add(a, b),
+            [a, "-", b] => Operation::Sub(a, b),
+            [a, "*", b] => Operation::Mul(a, b),
+            [a, "/", b] => Operation::Div(a, b),
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    job,
+                    "a number or `<monkey> <op> <monkey>`",
+                ))
+            }
+        };
+        if hmap.insert(key, (key, operation)).is_some() {
+            return Err(ParseError::at(input, key, "a monkey not named before"));
+        }
+        names.push(key);
+    }
+    if !hmap.contains_key("root") {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a monkey named `root`",
+        ));
+    }
+    let mut done = HashSet::new();
+    for name in names {
+        check_waiting(input, &hmap, name, &mut done, &mut vec![])?;
+    }
+    Ok(hmap)
+}
+
+/// Checks that the monkeys `monkey` waits for exist and that none of them
+/// waits for itself. `done` has the monkeys checked before, `path` the
+/// monkeys waiting for `monkey`.
+fn check_waiting<'a>(
+    input: &str,
+    monkeys: &MonkeyMap<'a>,
+    monkey: &'a str,
+    done: &mut HashSet<&'a str>,
+    path: &mut Vec<&'a str>,
+) -> Result<(), ParseError> {
+    if done.contains(monkey) {
+        return Ok(());
+    }
+    path.push(monkey);
+    for operand in monkeys[monkey]
+        .1
+        .operands()
+        .into_iter()
+        .flat_map(|(a, b)| [a, b])
+    {
+        if !monkeys.contains_key(operand) {
+            return Err(ParseError::at(input, operand, "the name of a monkey"));
+        }
+        if let Some(start) = path.iter().position(|&m| m == operand) {
+            let cycle = [&path[start..], &[operand]].concat().join(" -> ");
+            return Err(ParseError::at(
+                input,
+                operand,
+                format!("a monkey outside the cycle `{cycle}`"),
+            ));
+        }
+        check_waiting(input, monkeys, operand, done, path)?;
+    }
+    path.pop();
+    done.insert(monkey);
+    Ok(())
+}
+
+/// `a * x + b` of the unknown `x`, a constant if `a` is zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Linear {
+    a: Rational,
+    b: Rational,
+}
+
+/// `a <op> b`, an error on overflow or division by zero.
+fn apply(
+    op: fn(Rational, Rational) -> Option<Rational>,
+    a: Rational,
+    b: Rational,
+) -> aoc::Result<Rational> {
+    op(a, b).ok_or_else(|| eyre!("`{a}` and `{b}` overflow or divide by zero"))
+}
+
+impl Linear {
+    const UNKNOWN: Linear = Linear {
+        a: Rational::ONE,
+        b: Rational::ZERO,
+    };
+
+    fn constant(b: Rational) -> Self {
+        Linear {
+            a: Rational::ZERO,
+            b,
+        }
+    }
+
+    fn is_constant(&self) -> bool {
+        self.a.is_zero()
+    }
+
+    fn add(self, other: Linear) -> aoc::Result<Linear> {
+        Ok(Linear {
+            a: apply(Rational::checked_add, self.a, other.a)?,
+            b: apply(Rational::checked_add, self.b, other.b)?,
+        })
+    }
+
+    fn sub(self, other: Linear) -> aoc::Result<Linear> {
+        Ok(Linear {
+            a: apply(Rational::checked_sub, self.a, other.a)?,
+            b: apply(Rational::checked_sub, self.b, other.b)?,
+        })
+    }
+
+    fn mul(self, other: Linear) -> aoc::Result<Linear> {
+        let (form, factor) = match (self.is_constant(), other.is_constant()) {
+            (_, true) => (self, other.b),
+            (true, false) => (other, self.b),
+            (false, false) => bail!("the unknown is multiplied by itself, that isn't linear"),
+        };
+        Ok(Linear {
+            a: apply(Rational::checked_mul, form.a, factor)?,
+            b: apply(Rational::checked_mul, form.b, factor)?,
+        })
+    }
+
+    fn div(self, divisor: Rational) -> aoc::Result<Linear> {
+        Ok(Linear {
+            a: apply(Rational::checked_div, self.a, divisor)?,
+            b: apply(Rational::checked_div, self.b, divisor)?,
+        })
+    }
+
+    /// What the unknown has to be for the form to be `target`.
+    fn solve(self, target: Rational) -> aoc::Result<Yell> {
+        if self.is_constant() {
+            return Ok(if self.b == target {
+                Yell::Anything
+            } else {
+                Yell::Nothing
+            });
+        }
+        let difference = apply(Rational::checked_sub, target, self.b)?;
+        apply(Rational::checked_div, difference, self.a).map(Yell::Exactly)
+    }
+}
+
+/// What a monkey yells in terms of the unknown.
+#[derive(Debug, Clone, PartialEq)]
+enum Form {
+    Linear(Linear),
+    /// `outer` of `numerator / inner`, the unknown is a divisor.
+    Reciprocal {
+        outer: Linear,
+        numerator: Rational,
+        inner: Box<Form>,
+    },
+    /// A division by zero whatever the unknown is.
+    Undefined,
+}
+
+/// What the unknown can be to make a form yell the expected value.
+#[derive(Debug, PartialEq)]
+enum Yell {
+    Exactly(Rational),
+    Nothing,
+    Anything,
+}
+
+impl Form {
+    fn constant(b: Rational) -> Self {
+        Form::Linear(Linear::constant(b))
+    }
+
+    /// The value of a form without the unknown.
+    fn value(&self) -> Option<Rational> {
+        match self {
+            Form::Linear(form) if form.is_constant() => Some(form.b),
+            _ => None,
+        }
+    }
+
+    fn is_constant(&self) -> bool {
+        self.value().is_some()
+    }
+
+    /// The form with `f` applied to its outer linear form.
+    fn map(self, f: impl FnOnce(Linear) -> aoc::Result<Linear>) -> aoc::Result<Form> {
+        Ok(match self {
+            Form::Linear(form) => Form::Linear(f(form)?),
+            Form::Reciprocal {
+                outer,
+                numerator,
+                inner,
+            } => match f(outer)? {
+                // a multiple of zero no longer depends on the unknown
+                outer if outer.is_constant() => Form::Linear(outer),
+                outer => Form::Reciprocal {
+                    outer,
+                    numerator,
+                    inner,
+                },
+            },
+            Form::Undefined => Form::Undefined,
+        })
+    }
+
+    /// `self <op> other` for an `op` of linear forms.
+    fn combine(
+        self,
+        other: Form,
+        op: fn(Linear, Linear) -> aoc::Result<Linear>,
+    ) -> aoc::Result<Form> {
+        match (self, other) {
+            (Form::Undefined, _) | (_, Form::Undefined) => Ok(Form::Undefined),
+            (Form::Linear(a), Form::Linear(b)) => Ok(Form::Linear(op(a, b)?)),
+            (form, Form::Linear(k)) if k.is_constant() => form.map(|outer| op(outer, k)),
+            (Form::Linear(k), form) if k.is_constant() => form.map(|outer| op(k, outer)),
+            _ => bail!("the unknown is a divisor and appears twice, that isn't linear"),
+        }
+    }
+
+    fn add(self, other: Form) -> aoc::Result<Form> {
+        self.combine(other, Linear::add)
+    }
+
+    fn sub(self, other: Form) -> aoc::Result<Form> {
+        self.combine(other, Linear::sub)
+    }
+
+    fn mul(self, other: Form) -> aoc::Result<Form> {
+        self.combine(other, Linear::mul)
+    }
+
+    fn div(self, other: Form) -> aoc::Result<Form> {
+        match (self, other) {
+            (Form::Undefined, _) | (_, Form::Undefined) => Ok(Form::Undefined),
+            (form, Form::Linear(k)) if k.is_constant() => {
+                if k.b.is_zero() && !form.is_constant() {
+                    Ok(Form::Undefined)
+                } else {
+                    form.map(|outer| outer.div(k.b))
+                }
+            }
+            (Form::Linear(c), form) if c.is_constant() => Ok(Form::Reciprocal {
+                outer: Linear::UNKNOWN,
+                numerator: c.b,
+                inner: Box::new(form),
+            }),
+            _ => bail!("the unknown is divided by itself, that isn't linear"),
+        }
+    }
+
+    /// Solves `self = target` for the unknown, from the outside in.
+    fn solve(&self, target: Rational) -> aoc::Result<Yell> {
+        match self {
+            Form::Linear(form) => form.solve(target),
+            Form::Reciprocal {
+                outer,
+                numerator,
+                inner,
+            } => match outer.solve(target)? {
+                // `numerator / inner = value`, where inner must not be zero
+                Yell::Exactly(value) => match (numerator.is_zero(), value.is_zero()) {
+                    (true, true) => Ok(Yell::Anything),
+                    (true, false) | (false, true) => Ok(Yell::Nothing),
+                    (false, false) => inner.solve(apply(Rational::checked_div, *numerator, value)?),
+                },
+                yell => Ok(yell),
+            },
+            Form::Undefined => Ok(Yell::Nothing),
+        }
+    }
+}
+
+/// Folds the expression of every monkey once into a form of the unknown
+/// yelled by `variable`, all monkeys are constant without one.
+fn compile<'a>(
+    input: &MonkeyMap<'a>,
+    variable: Option<&str>,
+) -> aoc::Result<HashMap<&'a str, Form>> {
+    fn fold<'a>(
+        input: &MonkeyMap<'a>,
+        variable: Option<&str>,
+        monkey: &'a str,
+        forms: &mut HashMap<&'a str, Form>,
+    ) -> aoc::Result<Form> {
+        if let Some(form) = forms.get(monkey) {
+            return Ok(form.clone());
+        }
+        let (name, ref operation) = input[monkey];
+        let form = if Some(name) == variable {
+            Form::Linear(Linear::UNKNOWN)
+        } else {
+            let mut fold = |monkey| fold(input, variable, monkey, forms);
+            match *operation {
+                Operation::Add(a, b) => fold(a)?.add(fold(b)?)?,
+                Operation::Sub(a, b) => fold(a)?.sub(fold(b)?)?,
+                Operation::Mul(a, b) => fold(a)?.mul(fold(b)?)?,
+                Operation::Div(a, b) => fold(a)?.div(fold(b)?)?,
+                Operation::Val(v) => Form::constant(Rational::from(v)),
+            }
+        };
+        forms.insert(name, form.clone());
+        Ok(form)
+    }
+
+    let mut forms = HashMap::with_capacity(input.len());
+    for &monkey in input.keys() {
+        fold(input, variable, monkey, &mut forms)?;
+    }
+    Ok(forms)
+}
+
+/// `form` with `variable` as the name of the unknown.
+fn describe(form: &Form, variable: &str) -> String {
+    match form {
+        Form::Linear(form) => describe_linear(*form, variable),
+        Form::Reciprocal {
+            outer,
+            numerator,
+            inner,
+        } => {
+            let inner = describe(inner, variable);
+            let inner = if inner.contains(' ') {
+                format!("({inner})")
+            } else {
+                inner
+            };
+            match *outer {
+                Linear::UNKNOWN => format!("{numerator} / {inner}"),
+                outer => describe_linear(outer, &format!("({numerator} / {inner})")),
+            }
+        }
+        Form::Undefined => "a division by zero".into(),
+    }
+}
+
+/// The linear `form` of the unknown written as `term`.
+fn describe_linear(form: Linear, term: &str) -> String {
+    let term = match form.a {
+        a if a.is_zero() => return form.b.to_string(),
+        Rational::ONE => term.to_string(),
+        a => format!("{a} * {term}"),
+    };
+    match form.b {
+        b if b.is_zero() => term,
+        b if b.numer() < 0 => {
+            let b = b.checked_neg().expect("the numerator is never `i128::MIN`");
+            format!("{term} - {b}")
+        }
+        b => format!("{term} + {b}"),
+    }
+}
+
+/// The monkeys labeled with their operator and the value or the form they
+/// yell, the monkeys waiting for `variable` are highlighted.
+fn graph(input: &MonkeyMap, forms: &HashMap<&str, Form>, variable: Option<&str>) -> Graph {
+    let mut names: Vec<_> = input.keys().copied().collect();
+    names.sort_unstable();
+    let waiting = |name| variable.is_some_and(|_| !forms[name].is_constant());
+    let mut graph = Graph::new("monkeys");
+    for &name in &names {
+        let (_, ref operation) = input[name];
+        let value = describe(&forms[name], variable.unwrap_or_default());
+        let node = graph.node(name);
+        match operation {
+            _ if Some(name) == variable => node.attr("label", name),
+            // in part 2 root compares its operands
+            _ if name == "root" && variable.is_some() => node.attr("label", "root\n=="),
+            Operation::Val(_) => node.attr("label", format!("{name}\n{value}")),
+            _ => node.attr(
+                "label",
+                format!("{name}\n{}\n= {value}", operation.symbol()),
+            ),
+        };
+        if waiting(name) {
+            node.attr("color", "red").attr("fontcolor", "red");
+        }
+        if Some(name) == variable {
+            node.attr("shape", "box");
+        }
+    }
+    for &name in &names {
+        let Some((a, b)) = input[name].1.operands() else {
+            continue;
+        };
+        for (operand, side) in [(a, "left"), (b, "right")] {
+            let edge = graph.edge(name, operand);
+            if matches!(input[name].1, Operation::Sub(..) | Operation::Div(..)) {
+                edge.attr("label", side);
+            }
+            if waiting(name) && waiting(operand) {
+                edge.attr("color", "red");
+            }
+        }
+    }
+    graph
+}
+
+fn part1(input: &MonkeyMap) -> aoc::Result<i128> {
+    let forms = compile(input, None)?;
+    aoc::dot::snapshot(|| graph(input, &forms, None));
+    let root = forms["root"]
+        .value()
+        .expect("all monkeys are constant without an unknown");
+    root.to_integer()
+        .ok_or_else(|| eyre!("root yells `{root}`, not an integer"))
+}
+
+/// Solves the equality of the two operands of root for the unknown, whatever
+/// the operation of root is. The unknown may be on both sides of a linear
+/// equation, or a divisor on one side.
+fn part2(input: &MonkeyMap, variable: &str) -> aoc::Result<i128> {
+    ensure!(
+        input.contains_key(variable),
+        "there is no monkey `{variable}`"
+    );
+    let Some((left, right)) = input["root"].1.operands() else {
+        bail!("root yells a number instead of comparing two monkeys");
+    };
+    let forms = compile(input, Some(variable))?;
+    aoc::dot::snapshot(|| graph(input, &forms, Some(variable)));
+    // left = right is left - right = 0
+    let equation = forms[left].clone().sub(forms[right].clone())?;
+    match equation.solve(Rational::ZERO)? {
+        Yell::Exactly(value) => value
+            .to_integer()
+            .ok_or_else(|| eyre!("no integer solution, `{variable}` would have to yell `{value}`")),
+        Yell::Nothing => bail!("no integer solution"),
+        Yell::Anything => bail!("infinitely many solutions"),
+    }
+}
+
+struct Day21;
+
+impl aoc::Puzzle for Day21 {
+    type Input<'a> = MonkeyMap<'a>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        let variable = aoc::options::get("variable");
+        part2(input, variable.as_deref().unwrap_or("humn"))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day21>(2022, 21, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{compile, decode_input, describe, graph, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        let input = decode_input(TEST).unwrap();
+        assert_eq!(152, part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(301, part2(&decode_input(TEST).unwrap(), "humn").unwrap());
+    }
+
+    fn solve(input: &str, variable: &str) -> String {
+        match part2(&decode_input(input).unwrap(), variable) {
+            Ok(value) => value.to_string(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_part2_any_root() {
+        for op in ["-", "*", "/"] {
+            assert_eq!(
+                solve(
+                    &TEST.replace("pppw + sjmn", &format!("pppw {op} sjmn")),
+                    "humn"
+                ),
+                "301"
+            );
+        }
+        // humn yells 5 and dvpt is unknown instead
+        assert_eq!(solve(TEST, "dvpt"), "-293");
+        assert_eq!(solve(TEST, "nope"), "there is no monkey `nope`");
+    }
+
+    #[test]
+    fn test_part2_exact() {
+        // a negative intermediate and a division that only works out exactly
+        let input = "root: lhs + rhs\nlhs: half * two\nhalf: diff / two\n\
+                     diff: ten - humn\nten: 10\ntwo: 2\nrhs: 13\nhumn: 0\n";
+        assert_eq!(solve(input, "humn"), "-3");
+        let input = input.replace("half * two", "half * ten");
+        assert_eq!(
+            solve(&input, "humn"),
+            "no integer solution, `humn` would have to yell `37/5`"
+        );
+    }
+
+    #[test]
+    fn test_part2_both_sides() {
+        let input =
+            "root: lhs + rhs\nlhs: humn * three\nthree: 3\nrhs: humn + ten\nten: 10\nhumn: 0\n";
+        assert_eq!(solve(input, "humn"), "5");
+        let input = input.replace("humn * three", "humn + three");
+        assert_eq!(solve(&input, "humn"), "no integer solution");
+        let input = input.replace("humn + three", "humn + ten");
+        assert_eq!(solve(&input, "humn"), "infinitely many solutions");
+        let input = input.replace("humn + ten", "humn * humn");
+        assert_eq!(
+            solve(&input, "humn"),
+            "the unknown is multiplied by itself, that isn't linear"
+        );
+    }
+
+    #[test]
+    fn test_part2_degenerate() {
+        let input = "root: lhs + rhs\nlhs: humn * zero\nzero: 0\nrhs: 0\nhumn: 0\n";
+        assert_eq!(solve(input, "humn"), "infinitely many solutions");
+        assert_eq!(
+            solve(&input.replace("rhs: 0", "rhs: 1"), "humn"),
+            "no integer solution"
+        );
+        let input = "root: lhs + rhs\nlhs: zero / humn\nzero: 0\nrhs: 0\nhumn: 0\n";
+        assert_eq!(solve(input, "humn"), "infinitely many solutions");
+        let input = "root: lhs + rhs\nlhs: one / humn\none: 1\nrhs: 0\nhumn: 0\n";
+        assert_eq!(solve(input, "humn"), "no integer solution");
+        let input = input.replace("one / humn", "humn / zero\nzero: 0");
+        assert_eq!(solve(&input, "humn"), "no integer solution");
+    }
+
+    #[test]
+    fn test_part2_divisor() {
+        // 6 / (humn - 1) * 2 + 1 = 5
+        let input = "root: lhs + rhs\nlhs: prod + one\nprod: quot * two\nquot: six / diff\n\
+                     diff: humn - one\nsix: 6\ntwo: 2\none: 1\nrhs: 5\nhumn: 0\n";
+        assert_eq!(solve(input, "humn"), "4");
+        let forms = compile(&decode_input(input).unwrap(), Some("humn")).unwrap();
+        assert_eq!(describe(&forms["quot"], "humn"), "6 / (humn - 1)");
+        assert_eq!(describe(&forms["lhs"], "humn"), "2 * (6 / (humn - 1)) + 1");
+        assert_eq!(
+            solve(&input.replace("rhs: 5", "rhs: 6"), "humn"),
+            "no integer solution, `humn` would have to yell `17/5`"
+        );
+        let input = input.replace("prod + one", "prod + humn");
+        assert_eq!(
+            solve(&input, "humn"),
+            "the unknown is a divisor and appears twice, that isn't linear"
+        );
+        let input = "root: lhs + rhs\nlhs: humn / humn\nrhs: 1\nhumn: 0\n";
+        assert_eq!(
+            solve(input, "humn"),
+            "the unknown is divided by itself, that isn't linear"
+        );
+    }
+
+    #[test]
+    fn test_decode_references() {
+        let err = |input: &str| decode_input(input).unwrap_err().to_string();
+        assert_eq!(
+            err(&TEST.replace("sllz + lgvd", "sllz + lgvf")),
+            "line 4, column 14: expected the name of a monkey, found `lgvf`"
+        );
+        assert_eq!(
+            err(&TEST.replace("hmdt: 32", "hmdt: sjmn - zczc")),
+            "line 16, column 7: expected a monkey outside the cycle \
+             `sjmn -> drzm -> hmdt -> sjmn`, found `sjmn`"
+        );
+        assert_eq!(
+            err("a: 1\n"),
+            "line 2, column 1: expected a monkey named `root`, found the end of the input"
+        );
+    }
+
+    #[test]
+    fn test_graph() {
+        let input = decode_input(TEST).unwrap();
+        let forms = compile(&input, Some("humn")).unwrap();
+        let dot = graph(&input, &forms, Some("humn")).to_string();
+        assert!(dot.contains(
+            r#""pppw" [label="pppw\n/\n= 1/2 * humn - 1/2", color="red", fontcolor="red"];"#
+        ));
+        assert!(dot.contains(r#""sjmn" [label="sjmn\n*\n= 150"];"#));
+        assert!(
+            dot.contains(r#""humn" [label="humn", color="red", fontcolor="red", shape="box"];"#)
+        );
+        assert!(dot.contains(r#""root" [label="root\n==", color="red", fontcolor="red"];"#));
+        assert!(dot.contains(r#""root" -> "pppw" [color="red"];"#));
+        assert!(dot.contains(r#""root" -> "sjmn";"#));
+        assert!(dot.contains(r#""pppw" -> "lfqf" [label="right"];"#));
+    }
+
+    const TEST: &str = r#"
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32
+"#;
+
+    aoc::example_tests!(super::Day21, 1, 2);
+}
@@ -0,0 +1,235 @@
+use std::fmt::Display;
+
+use aoc::{parse, ParseError};
+use grid::{Direction, Grid, Pos};
+
+#[derive(Debug, PartialEq, Clone)]
+enum Tile {
+    Wall,
+    Path,
+    None,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'#' => Ok(Tile::Wall),
+            b'.' => Ok(Tile::Path),
+            b' ' => Ok(Tile::None),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Tile::None => " ",
+                Tile::Wall => "#",
+                Tile::Path => ".",
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+enum Move {
+    Left,
+    Right,
+    Straight(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Dungeon {
+    map: Grid<Tile>,
+    pos: Pos,
+    facing: Direction,
+}
+
+impl Dungeon {
+    fn new(map: Grid<Tile>) -> Self {
+        Self {
+            map,
+            pos: Pos::default(),
+            facing: Direction::Right,
+        }
+    }
+
+    fn set_start_position(&mut self) {
+        self.facing = Direction::Right;
+        self.pos = self.map.position(|t| *t != Tile::None).unwrap();
+    }
+
+    /// The next tile in the facing direction, wrapping around to the other
+    /// side of the map when walking off its edge.
+    fn next_position(&self) -> Pos {
+        match self.map.step(self.pos, self.facing) {
+            Some(next) if self.map[next] != Tile::None => next,
+            _ => self
+                .map
+                .ray(self.pos, self.facing.reverse())
+                .take_while(|&p| self.map[p] != Tile::None)
+                .last()
+                .unwrap_or(self.pos),
+        }
+    }
+
+    fn move_straight(&mut self, steps: usize) {
+        for _ in 0..steps {
+            let next = self.next_position();
+            match self.map[next] {
+                Tile::Wall => return,
+                Tile::Path => self.pos = next,
+                Tile::None => panic!("inconsistent map input!"),
+            }
+        }
+    }
+
+    fn execute_move(&mut self, next_move: &Move) {
+        match next_move {
+            Move::Right => self.facing = self.facing.turn_right(),
+            Move::Left => self.facing = self.facing.turn_left(),
+            Move::Straight(steps) => self.move_straight(*steps),
+        }
+    }
+
+    fn get_password(&self) -> usize {
+        (1000 * (self.pos.row + 1))
+            + (4 * (self.pos.col + 1))
+            + match self.facing {
+                Direction::Right => 0,
+                Direction::Down => 1,
+                Direction::Left => 2,
+                Direction::Up => 3,
+            }
+    }
+}
+
+impl Display for Dungeon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut map = self.map.map(ToString::to_string);
+        map[self.pos] = match self.facing {
+            Direction::Right => ">",
+            Direction::Down => "v",
+            Direction::Left => "<",
+            Direction::Up => "^",
+        }
+        .into();
+        map.fmt(f)
+    }
+}
+
+fn decode_input(input: &str) -> Result<(Dungeon, Vec<Move>), ParseError> {
+    let (map, path) = parse::split_once(input, input, "\n\n")?;
+    let map = parse::grid(input, map, Some(b' '))?;
+    if !map.values().any(|t| *t == Tile::Path) {
+        return Err(ParseError::at(
+            input,
+            &input[..0],
+            "a map with an open tile",
+        ));
+    }
+    let dungeon = Dungeon::new(map);
+    let l = path.trim();
+    let mut moves = vec![];
+    let mut start_pos = None;
+    for (i, c) in l.char_indices() {
+        match c {
+            'R' | 'L' => {
+                if let Some(pos) = start_pos.take() {
+                    moves.push(Move::Straight(parse::number(input, &l[pos..i])?));
+                }
+                moves.push(if c == 'R' { Move::Right } else { Move::Left });
+            }
+            '0'..='9' => {
+                if start_pos.is_none() {
+                    start_pos = Some(i);
+                }
+            }
+            _ => {
+                let found = &l[i..i + c.len_utf8()];
+                return Err(ParseError::at(
+                    input,
+                    found,
+                    "`R`, `L` or a number of steps",
+                ));
+            }
+        }
+    }
+    if let Some(pos) = start_pos {
+        moves.push(Move::Straight(parse::number(input, &l[pos..])?));
+    }
+    Ok((dungeon, moves))
+}
+
+fn part1(dungeon: &mut Dungeon, path: &[Move]) -> usize {
+    dungeon.set_start_position();
+    aoc::record::frame(dungeon);
+    path.iter().for_each(|m| {
+        dungeon.execute_move(m);
+        aoc::record::frame(dungeon);
+    });
+    dungeon.get_password()
+}
+
+struct Day22;
+
+impl aoc::Puzzle for Day22 {
+    type Input<'a> = (Dungeon, Vec<Move>);
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        let (dungeon, path) = input;
+        Ok(part1(&mut dungeon.clone(), path))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day22>(2022, 22, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1};
+
+    #[test]
+    fn test_part1() {
+        let (mut dungeon, path) = decode_input(TEST).unwrap();
+        assert_eq!(6032, part1(&mut dungeon, &path));
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let input = TEST.replace("10R5L5R10L4R5L5", "10R5X5R10L4R5L5");
+        let err = decode_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 15, column 5: expected `R`, `L` or a number of steps, found `X`"
+        );
+    }
+
+    const TEST: &str = r#"
+        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+"#;
+
+    aoc::example_tests!(super::Day22, 1);
+}
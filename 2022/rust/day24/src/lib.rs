@@ -0,0 +1,326 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc::ParseError;
+use grid::{Grid, Pos};
+use search::bfs;
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+enum Blizzard {
+    Left,
+    Right,
+    Top,
+    Down,
+}
+
+impl Display for Blizzard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Blizzard::Left => "<",
+                Blizzard::Right => ">",
+                Blizzard::Top => "^",
+                Blizzard::Down => "v",
+            }
+        )
+    }
+}
+
+impl TryFrom<u8> for Blizzard {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'<' => Ok(Blizzard::Left),
+            b'>' => Ok(Blizzard::Right),
+            b'^' => Ok(Blizzard::Top),
+            b'v' => Ok(Blizzard::Down),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The initial blizzards inside of the walls of the valley.
+type BlizzardMap = Grid<Option<Blizzard>>;
+
+#[derive(Debug, Clone)]
+struct Valley {
+    blizzards: BlizzardMap,
+    entry: Pos,
+    exit: Pos,
+    rows: usize,
+    cols: usize,
+    minutes: usize,
+}
+
+impl Valley {
+    fn new(blizzards: BlizzardMap, entry: Pos, exit: Pos) -> Self {
+        Self {
+            rows: blizzards.height(),
+            cols: blizzards.width(),
+            blizzards,
+            entry,
+            exit,
+            minutes: 0,
+        }
+    }
+
+    fn get_blizzards_at(&self, pos: Pos, minute: usize) -> HashSet<Blizzard> {
+        let mut blizzards = HashSet::new();
+        let cols_remainder = minute % self.cols;
+        let rows_remainder = minute % self.rows;
+        // search for blizzard from left
+        let orig_left_col = if pos.col >= cols_remainder {
+            pos.col - cols_remainder
+        } else {
+            self.cols - (cols_remainder - pos.col)
+        };
+        if let Some(blizzard) = &self.blizzards[Pos::new(pos.row, orig_left_col)] {
+            if *blizzard == Blizzard::Right {
+                blizzards.insert(blizzard.clone());
+            }
+        }
+        // search for blizzards from right
+        let orig_right_col = if pos.col + cols_remainder < self.cols {
+            pos.col + cols_remainder
+        } else {
+            cols_remainder - (self.cols - pos.col)
+        };
+        if let Some(blizzard) = &self.blizzards[Pos::new(pos.row, orig_right_col)] {
+            if *blizzard == Blizzard::Left {
+                blizzards.insert(blizzard.clone());
+            }
+        }
+        // search for blizzard from top
+        let orig_top_row = if pos.row >= rows_remainder {
+            pos.row - rows_remainder
+        } else {
+            self.rows - (rows_remainder - pos.row)
+        };
+        if let Some(blizzard) = &self.blizzards[Pos::new(orig_top_row, pos.col)] {
+            if *blizzard == Blizzard::Down {
+                blizzards.insert(blizzard.clone());
+            }
+        }
+        // search for blizzard from bottom
+        let orig_down_row = if pos.row + rows_remainder < self.rows {
+            pos.row + rows_remainder
+        } else {
+            rows_remainder - (self.rows - pos.row)
+        };
+        if let Some(blizzard) = &self.blizzards[Pos::new(orig_down_row, pos.col)] {
+            if *blizzard == Blizzard::Top {
+                blizzards.insert(blizzard.clone());
+            }
+        }
+        blizzards
+    }
+
+    /// Searches the earliest way through the valley when entering it after
+    /// the current minute. Returns the minute of leaving through the exit and
+    /// advances the clock to it.
+    fn cross(&mut self) -> usize {
+        let valley = &*self;
+        // `None` is the spot in front of the entry, outside of the valley
+        let search = bfs(
+            [(None, valley.minutes)],
+            |&(pos, minute): &(Option<Pos>, usize)| {
+                let minute = minute + 1;
+                let moves: Vec<Option<Pos>> = match pos {
+                    None => vec![None, Some(valley.entry)],
+                    Some(pos) => valley
+                        .blizzards
+                        .neighbours4(pos)
+                        .chain([pos])
+                        .map(Some)
+                        .collect(),
+                };
+                moves
+                    .into_iter()
+                    .filter(move |next| {
+                        next.is_none_or(|p| valley.get_blizzards_at(p, minute).is_empty())
+                    })
+                    .map(move |next| (next, minute))
+            },
+            |&(pos, _)| pos == Some(valley.exit),
+        );
+        let path = search.path.expect("the exit is reachable");
+        for &(expedition, minute) in &path.nodes {
+            aoc::record::frame(&Snapshot {
+                valley,
+                minute,
+                expedition,
+            });
+        }
+        let (_, arrival) = path.nodes[path.nodes.len() - 1];
+        self.minutes = arrival + 1;
+        self.minutes
+    }
+
+    fn swap_entry_and_exit(&mut self) {
+        std::mem::swap(&mut self.entry, &mut self.exit);
+    }
+}
+
+impl Display for Valley {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Snapshot {
+            valley: self,
+            minute: self.minutes,
+            expedition: None,
+        }
+        .fmt(f)
+    }
+}
+
+/// The blizzards of a [`Valley`] at a minute with the expedition `E` in it.
+struct Snapshot<'a> {
+    valley: &'a Valley,
+    minute: usize,
+    expedition: Option<Pos>,
+}
+
+impl Display for Snapshot<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let valley = self.valley;
+        writeln!(f, "{:#<1$}", "", valley.cols + 2)?;
+        for r in 0..valley.rows {
+            write!(f, "#")?;
+            for c in 0..valley.cols {
+                let pos = Pos::new(r, c);
+                let blizzards = valley.get_blizzards_at(pos, self.minute);
+                match blizzards.len() {
+                    _ if self.expedition == Some(pos) => write!(f, "E")?,
+                    0 => write!(f, ".")?,
+                    1 => blizzards.iter().next().unwrap().fmt(f)?,
+                    n => write!(f, "{}", n)?,
+                }
+            }
+            write!(f, "#")?;
+            writeln!(f)?;
+        }
+        writeln!(f, "{:#<1$}", "", valley.cols + 2)?;
+        writeln!(f)
+    }
+}
+
+fn decode_input(input: &str) -> Result<Valley, ParseError> {
+    let lines = input
+        .split_terminator('\n')
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+    if lines.len() < 3 || lines[0].len() < 3 {
+        let found = &input[input.len()..];
+        return Err(ParseError::at(input, found, "a valley surrounded by walls"));
+    }
+    let width = lines[0].len();
+    let valley_rows = lines.len() - 2;
+    let valley_cols = width - 2;
+    let gap = |line: &str| {
+        line.find('.')
+            .filter(|&c| c > 0 && c < width - 1)
+            .ok_or_else(|| ParseError::at(input, line, "a wall with a gap"))
+    };
+    let mut blizzards = Grid::new(valley_cols, valley_rows, None);
+    for (r, row) in lines.iter().skip(1).take(valley_rows).enumerate() {
+        if row.len() != width {
+            return Err(ParseError::at(
+                input,
+                row,
+                format!("a line of {width} cells"),
+            ));
+        }
+        for (c, ch) in row.char_indices() {
+            let cell = &row[c..c + ch.len_utf8()];
+            match (c == 0 || c == width - 1, ch) {
+                (true, '#') | (false, '.') => {}
+                (true, _) => return Err(ParseError::at(input, cell, "a wall `#`")),
+                (false, _) => {
+                    let blizzard = u8::try_from(ch)
+                        .ok()
+                        .and_then(|b| Blizzard::try_from(b).ok())
+                        .ok_or_else(|| ParseError::at(input, cell, "`.`, `<`, `>`, `^` or `v`"))?;
+                    blizzards[Pos::new(r, c - 1)] = Some(blizzard);
+                }
+            }
+        }
+    }
+    Ok(Valley::new(
+        blizzards,
+        Pos::new(0, gap(lines[0])? - 1),
+        Pos::new(lines.len() - 3, gap(lines[lines.len() - 1])? - 1),
+    ))
+}
+
+fn part1(valley: &mut Valley) -> usize {
+    valley.cross()
+}
+
+fn part2(valley: &mut Valley) -> usize {
+    let mut shortest_path = part1(valley);
+    for _ in 0..2 {
+        valley.swap_entry_and_exit();
+        shortest_path = part1(valley);
+    }
+    shortest_path
+}
+
+struct Day24;
+
+impl aoc::Puzzle for Day24 {
+    type Input<'a> = Valley;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(&mut input.clone()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(&mut input.clone()))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day24>(2022, 24, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(18, part1(&mut decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(54, part2(&mut decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let input = TEST.replace("#>>.<^<#", "#>>x<^<#");
+        let err = decode_input(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 4));
+        assert_eq!(err.expected, "`.`, `<`, `>`, `^` or `v`");
+        let err = decode_input("#.###\n#...#\n#####\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a wall with a gap, found `#####`"
+        );
+    }
+
+    const TEST: &str = r#"
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#
+"#;
+
+    aoc::example_tests!(super::Day24, 1, 2);
+}
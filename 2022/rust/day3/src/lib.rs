@@ -0,0 +1,74 @@
+use aoc::ParseError;
+
+fn priority(item: u8) -> Option<usize> {
+    match item {
+        b'A'..=b'Z' => Some((item - b'A') as usize + 27),
+        b'a'..=b'z' => Some((item - b'a') as usize + 1),
+        _ => None,
+    }
+}
+
+/// The rucksacks with the priorities of their items.
+fn decode_input(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if line.len() % 2 != 0 {
+                return Err(ParseError::at(input, line, "an even number of items"));
+            }
+            line.char_indices()
+                .map(|(i, c)| {
+                    u8::try_from(c)
+                        .ok()
+                        .and_then(priority)
+                        .ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], "an item"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn part1(input: &[Vec<usize>]) -> usize {
+    input
+        .iter()
+        .map(|rucksack| rucksack.split_at(rucksack.len() / 2))
+        .map(|(i1, i2)| *i1.iter().find(|i| i2.contains(i)).unwrap())
+        .sum::<usize>()
+}
+
+fn part2(input: &[Vec<usize>]) -> usize {
+    input
+        .chunks(3)
+        .map(|group| {
+            *group[0]
+                .iter()
+                .find(|i| group[1].contains(i) && group[2].contains(i))
+                .unwrap()
+        })
+        .sum::<usize>()
+}
+
+struct Day3;
+
+impl aoc::Puzzle for Day3 {
+    type Input<'a> = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day3>(2022, 3, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    aoc::example_tests!(super::Day3, 1, 2);
+}
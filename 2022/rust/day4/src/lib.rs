@@ -0,0 +1,77 @@
+use aoc::{parse, ParseError};
+use intervals::IntervalSet;
+
+type Pair = (IntervalSet<usize>, IntervalSet<usize>);
+
+fn decode_section(input: &str, section: &str) -> Result<IntervalSet<usize>, ParseError> {
+    let (start, end) = parse::split_once(input, section, "-")?;
+    let start: usize = parse::number(input, start)?;
+    Ok(IntervalSet::from(start..=parse::number(input, end)?))
+}
+
+fn decode_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    input
+        .split_ascii_whitespace()
+        .map(|l| {
+            let (a, b) = parse::split_once(input, l, ",")?;
+            Ok((decode_section(input, a)?, decode_section(input, b)?))
+        })
+        .collect()
+}
+
+fn count_pairs(input: &[Pair], fn_filter: fn(&&Pair) -> bool) -> usize {
+    input.iter().filter(fn_filter).count()
+}
+
+fn part1(input: &[Pair]) -> usize {
+    count_pairs(input, |p| p.0.is_superset(&p.1) || p.1.is_superset(&p.0))
+}
+
+fn part2(input: &[Pair]) -> usize {
+    count_pairs(input, |p| p.0.overlaps(&p.1))
+}
+
+struct Day4;
+
+impl aoc::Puzzle for Day4 {
+    type Input<'a> = Vec<Pair>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day4>(2022, 4, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(2, super::part1(&super::decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(4, super::part2(&super::decode_input(TEST).unwrap()));
+    }
+
+    static TEST: &str = r#"
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+"#;
+
+    aoc::example_tests!(super::Day4, 1, 2);
+}
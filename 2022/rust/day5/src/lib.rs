@@ -0,0 +1,149 @@
+use aoc::{parse, util::IterExt, ParseError};
+
+fn parse_stacks(input: &str, lines: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut stack_lines = lines.split_terminator('\n').rev();
+    let stack_header = stack_lines.next().unwrap_or(lines);
+    let last_label = stack_header
+        .split_whitespace()
+        .last()
+        .ok_or_else(|| ParseError::at(input, stack_header, "the stack numbers"))?;
+    let no_of_stacks: usize = parse::number(input, last_label)?;
+    let mut stacks = vec![Vec::new(); no_of_stacks];
+    for l in stack_lines {
+        let mut chunks = l.chars().arrays();
+        let mut i = 0;
+        for [_, e, _, _] in chunks.by_ref() {
+            if !e.is_whitespace() {
+                stacks
+                    .get_mut(i)
+                    .ok_or_else(|| ParseError::at(input, l, "a crate on a numbered stack"))?
+                    .push(e);
+            }
+            i += 1;
+        }
+        if let Some(v) = chunks.into_remainder().nth_back(1) {
+            stacks
+                .get_mut(i)
+                .ok_or_else(|| ParseError::at(input, l, "a crate on a numbered stack"))?
+                .push(v);
+        }
+    }
+    Ok(stacks)
+}
+
+type Move = (usize, usize, usize);
+
+fn parse_moves(input: &str, lines: &str, stacks: usize) -> Result<Vec<Move>, ParseError> {
+    let stack = |token| match parse::number(input, token)? {
+        n @ 1.. if n <= stacks => Ok(n),
+        _ => Err(ParseError::at(
+            input,
+            token,
+            format!("a stack from 1 to {stacks}"),
+        )),
+    };
+    lines
+        .lines()
+        .map(|l| {
+            let l = parse::strip_prefix(input, l, "move ")?;
+            let (count, l) = parse::split_once(input, l, " from ")?;
+            let (from, to) = parse::split_once(input, l, " to ")?;
+            Ok((parse::number(input, count)?, stack(from)?, stack(to)?))
+        })
+        .collect()
+}
+
+fn decode_input(input: &str) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+    let (stacks, moves) = parse::split_once(input, input, "\n\n")?;
+    let stacks = parse_stacks(input, stacks)?;
+    let moves = parse_moves(input, moves, stacks.len())?;
+    Ok((stacks, moves))
+}
+
+fn part1(mut stacks: Vec<Vec<char>>, moves: &[Move]) -> String {
+    moves.iter().for_each(|(no, from, to)| {
+        for _ in 0..*no {
+            let mv = stacks[*from - 1].pop().unwrap();
+            stacks[*to - 1].push(mv);
+        }
+    });
+    stacks.iter().fold(String::new(), |mut acc, stack| {
+        acc.push(*stack.last().unwrap());
+        acc
+    })
+}
+
+fn part2(mut stacks: Vec<Vec<char>>, moves: &[Move]) -> String {
+    moves.iter().for_each(|(no, from, to)| {
+        let from_len = stacks[*from - 1].len();
+        let p = stacks[*from - 1].split_off(from_len - *no);
+        stacks[*to - 1].extend_from_slice(&p);
+    });
+    stacks.iter().fold(String::new(), |mut acc, stack| {
+        acc.push(*stack.last().unwrap());
+        acc
+    })
+}
+
+struct Day5;
+
+impl aoc::Puzzle for Day5 {
+    type Input<'a> = (Vec<Vec<char>>, Vec<Move>);
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        let (stacks, moves) = input;
+        Ok(part1(stacks.clone(), moves))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        let (stacks, moves) = input;
+        Ok(part2(stacks.clone(), moves))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day5>(2022, 5, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        let (stacks, moves) = decode_input(TEST).unwrap();
+        assert_eq!("CMZ", part1(stacks, &moves));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (stacks, moves) = decode_input(TEST).unwrap();
+        assert_eq!("MCD", part2(stacks, &moves));
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let input = TEST.replace("from 2 to 1", "from 4 to 1");
+        let err = decode_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, column 13: expected a stack from 1 to 3, found `4`"
+        );
+    }
+
+    const TEST: &str = r#"
+    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"#;
+
+    aoc::example_tests!(super::Day5, 1, 2);
+}
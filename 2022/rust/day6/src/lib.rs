@@ -0,0 +1,69 @@
+use aoc::{util::partition_dedup, ParseError};
+
+fn find_distinct_char_patch(input: &[char], patch_len: usize) -> usize {
+    let mut offset = 0_usize;
+    for (index, token) in input.windows(patch_len).enumerate() {
+        let mut token = token.to_vec();
+        token.sort();
+        if partition_dedup(&mut token).1.is_empty() {
+            offset = index + patch_len;
+            break;
+        }
+    }
+    offset
+}
+
+fn decode_input(input: &str) -> Result<Vec<char>, ParseError> {
+    let signal = input.trim_end();
+    match signal.find(|c: char| !c.is_ascii_lowercase()) {
+        Some(i) => Err(ParseError::at(input, &signal[i..=i], "a lowercase letter")),
+        None => Ok(signal.chars().collect()),
+    }
+}
+
+fn part1(input: &[char]) -> usize {
+    find_distinct_char_patch(input, 4)
+}
+
+fn part2(input: &[char]) -> usize {
+    find_distinct_char_patch(input, 14)
+}
+
+struct Day6;
+
+impl aoc::Puzzle for Day6 {
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day6>(2022, 6, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(7, part1(&decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(19, part2(&decode_input(TEST).unwrap()));
+    }
+
+    const TEST: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+    aoc::example_tests!(super::Day6, 1, 2);
+}
@@ -0,0 +1,160 @@
+use aoc::{parse, ParseError};
+
+const NEEDED_UNUSED_SPACE: usize = 30_000_000;
+const DISK_SPACE: usize = 70_000_000;
+
+#[derive(Debug)]
+struct Dir<'a> {
+    path: Vec<&'a str>,
+    size: usize,
+    subdirs: Vec<&'a str>,
+}
+
+fn calc_dir_size(dir: &Dir, dirs: &[Dir]) -> usize {
+    let mut size_subdirs = 0;
+    for subdir in dir.subdirs.iter() {
+        let mut path = dir.path.clone();
+        path.push(subdir);
+        size_subdirs += dirs
+            .iter()
+            .find(|s| s.path == path)
+            .map(|sub| calc_dir_size(sub, dirs))
+            .unwrap_or(0)
+    }
+    dir.size + size_subdirs
+}
+
+fn calc_dir_stats(input: &str) -> Result<Vec<Dir<'_>>, ParseError> {
+    let mut directories: Vec<Dir> = Vec::new();
+    let mut current_path = Vec::new();
+    for line in input.split_terminator('\n').filter(|f| !f.is_empty()) {
+        let tokens = line
+            .split_whitespace()
+            .filter(|f| !f.is_empty())
+            .collect::<Vec<_>>();
+        let token = |i: usize, expected: &str| {
+            tokens
+                .get(i)
+                .copied()
+                .ok_or_else(|| ParseError::at(input, &line[line.len()..], expected))
+        };
+        let listed = |directories: &[Dir<'_>], current_path: &[&str]| {
+            let pos = directories.iter().position(|d| d.path == *current_path);
+            pos.ok_or_else(|| ParseError::at(input, line, "`$ ls` before the listing"))
+        };
+        match tokens[0] {
+            "$" => match token(1, "a command")? {
+                "cd" => match token(2, "a directory")? {
+                    ".." => {
+                        current_path.pop();
+                    }
+                    dir => current_path.push(dir),
+                },
+                "ls" => directories.push(Dir {
+                    path: current_path.clone(),
+                    size: 0,
+                    subdirs: Vec::new(),
+                }),
+                command => return Err(ParseError::at(input, command, "`cd` or `ls`")),
+            },
+            "dir" => {
+                let name = token(1, "a directory name")?;
+                let i = listed(&directories, &current_path)?;
+                directories[i].subdirs.push(name);
+            }
+            size => {
+                let size: usize = parse::number(input, size)?;
+                let i = listed(&directories, &current_path)?;
+                directories[i].size += size;
+            }
+        }
+    }
+    Ok(directories)
+}
+
+fn part1(dir_stats: &[Dir]) -> usize {
+    dir_stats
+        .iter()
+        .map(|d| calc_dir_size(d, dir_stats))
+        .filter(|s| *s < 100000)
+        .sum::<usize>()
+}
+
+fn part2(dir_stats: &[Dir]) -> usize {
+    let total_used_size = calc_dir_size(
+        dir_stats.iter().find(|d| d.path == vec!["/"]).unwrap(),
+        dir_stats,
+    );
+    let free_space = DISK_SPACE - total_used_size;
+    let needed_space = NEEDED_UNUSED_SPACE - free_space;
+    dir_stats
+        .iter()
+        .map(|d| calc_dir_size(d, dir_stats))
+        .filter(|s| *s >= needed_space)
+        .min()
+        .unwrap()
+}
+
+struct Day7;
+
+impl aoc::Puzzle for Day7 {
+    type Input<'a> = Vec<Dir<'a>>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(calc_dir_stats(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day7>(2022, 7, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn test_part1() {
+        let dir_stats = super::calc_dir_stats(TEST).unwrap();
+        assert_eq!(95437, super::part1(&dir_stats));
+    }
+
+    #[test]
+    fn test_part2() {
+        let dir_stats = super::calc_dir_stats(TEST).unwrap();
+        assert_eq!(24933642, super::part2(&dir_stats));
+    }
+
+    const TEST: &str = r#"
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+"#;
+
+    aoc::example_tests!(super::Day7, 1, 2);
+}
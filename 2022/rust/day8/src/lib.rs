@@ -0,0 +1,139 @@
+use aoc::{
+    image::{Image, Palette},
+    parse, ParseError,
+};
+use grid::{Direction, Grid, Pos};
+
+#[derive(Debug)]
+struct Tree {
+    height: u8,
+    is_visible: bool,
+    scenic_score: usize,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self {
+            height: 0,
+            is_visible: true,
+            scenic_score: 0,
+        }
+    }
+}
+
+impl TryFrom<u8> for Tree {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'0'..=b'9' => Ok(Tree {
+                height: value - b'0',
+                ..Default::default()
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+fn get_scenic_score(pos: Pos, wood: &Grid<Tree>) -> (usize, bool) {
+    let my_height = wood[pos].height;
+    Direction::ALL
+        .into_iter()
+        .map(|dir| {
+            let mut view = (0, true);
+            for tree in wood.ray(pos, dir) {
+                view.0 += 1;
+                if my_height <= wood[tree].height {
+                    view.1 = false;
+                    break;
+                }
+            }
+            view
+        })
+        .fold((1, false), |(score, visible), view| {
+            (score * view.0, visible || view.1)
+        })
+}
+
+fn decode_input(input: &str) -> Result<Grid<Tree>, ParseError> {
+    let mut wood: Grid<Tree> = parse::grid(input, input, None)?;
+    for pos in wood.positions().collect::<Vec<_>>() {
+        let (scenic_score, is_visible) = get_scenic_score(pos, &wood);
+        wood[pos].is_visible = is_visible;
+        wood[pos].scenic_score = scenic_score;
+    }
+    Ok(wood)
+}
+
+fn part1(wood: &Grid<Tree>) -> usize {
+    aoc::image::snapshot(|| {
+        Image::from_grid(wood, |t| {
+            let color = if t.is_visible {
+                Palette::HEAT
+            } else {
+                Palette::GRAY
+            };
+            color.scale(t.height.into(), 9.0)
+        })
+        .scaled(4)
+    });
+    wood.values().filter(|t| t.is_visible).count()
+}
+
+fn part2(wood: &Grid<Tree>) -> usize {
+    let max = wood.values().map(|t| t.scenic_score).max().unwrap();
+    // a few trees have a far higher score than all others
+    aoc::image::snapshot(|| {
+        let scale = |score: usize| (score as f64).sqrt();
+        Image::from_grid(wood, |t| {
+            Palette::HEAT.scale(scale(t.scenic_score), scale(max))
+        })
+        .scaled(4)
+    });
+    max
+}
+
+struct Day8;
+
+impl aoc::Puzzle for Day8 {
+    type Input<'a> = Grid<Tree>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day8>(2022, 8, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    use crate::{decode_input, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(21, part1(&decode_input(TEST).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(8, part2(&decode_input(TEST).unwrap()));
+    }
+
+    const TEST: &str = r#"
+30373
+25512
+65332
+33549
+35390
+"#;
+
+    aoc::example_tests!(super::Day8, 1, 2);
+}
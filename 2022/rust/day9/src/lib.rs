@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+
+use aoc::{parse, ParseError};
+
+#[derive(Clone, Debug)]
+enum Direction {
+    Right,
+    Left,
+    Up,
+    Down,
+}
+
+/// Moves the head of the `rope` one step in `direction`, every other knot
+/// follows the knot before it once they no longer touch.
+fn move_rope(rope: &mut [(i32, i32)], direction: &Direction) {
+    match direction {
+        Direction::Up => rope[0].1 += 1,
+        Direction::Down => rope[0].1 -= 1,
+        Direction::Left => rope[0].0 -= 1,
+        Direction::Right => rope[0].0 += 1,
+    };
+    for i in 1..rope.len() {
+        let (diff_x, diff_y) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+        if diff_x.abs() <= 1 && diff_y.abs() <= 1 {
+            // the knots behind don't move either
+            break;
+        }
+        rope[i].0 += diff_x.signum();
+        rope[i].1 += diff_y.signum();
+    }
+}
+
+/// The number of positions the tail of the `rope` visits, the start included.
+fn exec_rope_moves(moves: &[Direction], rope: &mut [(i32, i32)]) -> usize {
+    let mut set = HashSet::from([*rope.last().unwrap()]);
+    moves.iter().for_each(|d| {
+        move_rope(rope, d);
+        set.insert(*rope.last().unwrap());
+    });
+    set.len()
+}
+
+fn decode_input(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = Vec::new();
+    for l in input.lines().filter(|l| !l.is_empty()) {
+        let (direction, steps) = parse::split_once(input, l, " ")?;
+        let direction = match direction {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => return Err(ParseError::at(input, direction, "`R`, `L`, `U` or `D`")),
+        };
+        let steps: usize = parse::number(input, steps)?;
+        moves.extend(std::iter::repeat_n(direction, steps));
+    }
+    Ok(moves)
+}
+
+fn part1(input: &[Direction]) -> usize {
+    let mut rope = vec![(0, 0); 2];
+    exec_rope_moves(input, &mut rope)
+}
+
+fn part2(input: &[Direction]) -> usize {
+    let mut rope = vec![(0, 0); 10];
+    exec_rope_moves(input, &mut rope)
+}
+
+struct Day9;
+
+impl aoc::Puzzle for Day9 {
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day9>(2022, 9, aoc::default_input!());
+
+#[cfg(test)]
+mod test {
+    const TEST: &str = r#"
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"#;
+
+    #[test]
+    fn test_rope_part1() {
+        let input = super::decode_input(TEST).unwrap();
+        assert_eq!(super::part1(&input), 13);
+    }
+
+    #[test]
+    fn test_rope_part2() {
+        let input = super::decode_input(TEST).unwrap();
+        // the tail never leaves the start
+        assert_eq!(super::part2(&input), 1);
+    }
+
+    const PART2_TEST: &str = r#"
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+"#;
+
+    #[test]
+    fn test_rope_part2_complex() {
+        let input = super::decode_input(PART2_TEST).unwrap();
+        assert_eq!(super::part2(&input), 36);
+    }
+
+    aoc::example_tests!(super::Day9, 1, 2);
+}
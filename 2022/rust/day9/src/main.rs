@@ -1,2182 +1,3 @@
-use std::collections::HashSet;
-
-#[derive(Clone, Debug)]
-enum Direction {
-    Right,
-    Left,
-    Up,
-    Down,
+fn main() -> std::process::ExitCode {
+    aoc::run_day(&day9::SOLUTION)
 }
-
-fn calc_diff(p1: &(i32, i32), p2: &(i32, i32)) -> (i32, i32) {
-    let diff_x = if p1.0 >= p2.0 {
-        p1.0 - p2.0
-    } else {
-        p2.0 - p1.0
-    };
-    let diff_y = if p1.1 >= p2.1 {
-        p1.1 - p2.1
-    } else {
-        p2.1 - p1.1
-    };
-    (diff_x, diff_y)
-}
-
-fn move_rope(rope: &mut [(i32, i32)], direction: &Direction) -> bool {
-    dbg!(format!("{rope:?}"));
-    let mut old_head = rope[0];
-    match direction {
-        Direction::Up => rope[0].1 += 1,
-        Direction::Down => rope[0].1 -= 1,
-        Direction::Left => rope[0].0 -= 1,
-        Direction::Right => rope[0].0 += 1,
-    };
-    let mut tail_updated = false;
-    let mut was_prev_move_diagonal = false;
-    for i in 1..rope.len() {
-        let (diff_x, diff_y) = calc_diff(&rope[i], &rope[i - 1]);
-        dbg!(format!("{diff_x}/{diff_y}|{direction:?}"));
-        dbg!(format!("{rope:?}"));
-        match (diff_x, diff_y) {
-            (2, 2) => {
-                assert!(was_prev_move_diagonal);
-                old_head = std::mem::replace(&mut rope[i], old_head);
-            }
-            (2, 1) | (1, 2) => {
-                if was_prev_move_diagonal {
-                    let cur_knot = rope[i];
-                    let prev_knot = rope[i - 1];
-                    if old_head.0 == rope[i].0 {
-                        old_head = std::mem::replace(
-                            &mut rope[i],
-                            (prev_knot.0, cur_knot.1 + prev_knot.1 - old_head.1),
-                        );
-                    } else if old_head.1 == rope[i].1 {
-                        old_head = std::mem::replace(
-                            &mut rope[i],
-                            (cur_knot.0 + prev_knot.0 - old_head.0, prev_knot.1),
-                        );
-                    }
-                } else {
-                    old_head = std::mem::replace(&mut rope[i], old_head);
-                    was_prev_move_diagonal = true;
-                }
-                tail_updated = true;
-            }
-            (2, 0) | (0, 2) => {
-                old_head = std::mem::replace(&mut rope[i], old_head);
-                was_prev_move_diagonal = false;
-                tail_updated = true;
-            }
-            (x, y) => {
-                assert!(x <= 1 && y <= 1);
-                was_prev_move_diagonal = false;
-                tail_updated = false;
-            }
-        };
-    }
-    println!();
-    tail_updated
-}
-
-fn exec_rope_moves(moves: &[Direction], rope: &mut [(i32, i32)]) -> usize {
-    let mut set = HashSet::new();
-    moves.iter().for_each(|d| {
-        if move_rope(rope, d) {
-            set.insert(*rope.last().unwrap());
-        }
-    });
-    set.len()
-}
-
-fn decode_input(input: &str) -> Vec<Direction> {
-    input
-        .split_terminator('\n')
-        .filter(|l| !l.is_empty())
-        .map(|l| {
-            l.split_whitespace().fold(Vec::new(), |mut acc, c| {
-                match c {
-                    "R" => acc.push(Direction::Right),
-                    "L" => acc.push(Direction::Left),
-                    "U" => acc.push(Direction::Up),
-                    "D" => acc.push(Direction::Down),
-                    "0" => {
-                        acc.pop();
-                    }
-                    "1" => {}
-                    steps => {
-                        let direction = acc.last().unwrap().clone();
-                        (1..steps.parse::<usize>().unwrap())
-                            .for_each(|_| acc.push(direction.clone()));
-                    }
-                };
-                acc
-            })
-        })
-        .fold(Vec::new(), |mut acc, v| {
-            acc.extend_from_slice(v.as_slice());
-            acc
-        })
-}
-
-fn part1(input: &[Direction]) -> usize {
-    let mut rope = vec![(0, 0); 2];
-    exec_rope_moves(input, &mut rope)
-}
-
-fn part2(input: &[Direction]) -> usize {
-    let mut rope = vec![(0, 0); 10];
-    exec_rope_moves(input, &mut rope)
-}
-
-fn main() {
-    let input = decode_input(INPUT);
-    println!("{}", part1(&input));
-    println!("{}", part2(&input));
-}
-
-#[cfg(test)]
-mod test {
-    const TEST: &str = r#"
-R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2
-"#;
-
-    #[test]
-    fn test_rope_part1() {
-        let input = super::decode_input(TEST);
-        assert_eq!(super::part1(&input), 12);
-    }
-
-    #[test]
-    fn test_rope_part2() {
-        let input = super::decode_input(TEST);
-        assert_eq!(super::part2(&input), 0);
-    }
-
-    const PART2_TEST: &str = r#"
-R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20
-    "#;
-
-    #[test]
-    fn test_rope_part2_complex() {
-        let input = super::decode_input(PART2_TEST);
-        assert_eq!(dbg!(super::part2(&input)), 35);
-    }
-}
-
-const INPUT: &str = r#"
-L 2
-R 2
-U 1
-R 2
-U 2
-D 2
-U 1
-L 1
-U 1
-L 1
-D 1
-U 1
-L 1
-R 1
-L 2
-D 2
-R 1
-L 2
-D 2
-L 2
-U 1
-D 2
-R 1
-D 1
-U 1
-D 1
-U 2
-L 1
-U 2
-R 1
-D 2
-L 1
-D 1
-U 1
-L 1
-R 1
-L 1
-U 2
-L 1
-U 1
-L 2
-R 2
-D 2
-U 2
-R 1
-U 1
-D 2
-L 1
-U 1
-D 2
-U 1
-L 2
-U 1
-D 2
-U 2
-L 1
-D 2
-L 1
-U 1
-D 1
-U 1
-D 2
-R 2
-U 1
-L 1
-R 2
-D 2
-R 1
-L 1
-R 1
-D 1
-U 1
-L 2
-U 1
-L 1
-R 1
-D 2
-U 1
-L 1
-D 2
-R 2
-D 1
-U 1
-R 2
-U 1
-D 1
-R 2
-D 1
-R 1
-U 2
-D 1
-L 2
-U 2
-R 1
-L 1
-R 2
-U 2
-L 2
-U 2
-D 1
-U 1
-D 2
-R 2
-U 2
-R 1
-D 1
-R 2
-U 2
-D 2
-U 1
-R 1
-U 2
-D 3
-R 1
-L 1
-D 3
-U 3
-L 3
-D 2
-U 3
-D 1
-R 2
-D 2
-L 1
-U 3
-R 1
-U 1
-R 3
-D 1
-L 3
-R 1
-U 1
-L 2
-D 2
-L 1
-U 2
-D 1
-L 2
-D 1
-U 1
-R 2
-D 2
-U 2
-D 1
-L 1
-R 2
-U 3
-D 3
-U 2
-L 1
-D 1
-U 2
-R 3
-U 1
-D 1
-U 3
-R 2
-U 3
-D 2
-U 1
-R 1
-U 2
-R 2
-U 1
-L 1
-U 3
-L 2
-R 1
-U 2
-D 3
-L 1
-U 3
-D 3
-U 3
-L 1
-D 2
-L 2
-R 2
-D 3
-R 3
-D 2
-R 2
-D 3
-U 3
-R 1
-D 3
-U 2
-R 3
-D 2
-R 3
-U 1
-R 2
-D 2
-U 3
-D 3
-R 1
-U 3
-D 1
-U 3
-D 3
-R 2
-U 2
-D 1
-R 3
-D 2
-U 3
-L 1
-R 3
-L 2
-U 1
-L 2
-U 1
-R 1
-U 2
-R 3
-D 2
-U 1
-L 3
-D 2
-L 2
-D 3
-R 1
-U 1
-R 1
-D 1
-L 3
-D 2
-L 3
-D 4
-R 2
-D 4
-L 1
-D 4
-U 2
-R 2
-U 3
-L 4
-D 1
-R 1
-L 4
-U 1
-D 3
-L 2
-R 3
-U 1
-R 2
-U 4
-D 1
-L 2
-R 2
-D 4
-L 2
-D 2
-R 1
-D 2
-L 1
-U 4
-L 3
-U 3
-R 4
-L 3
-U 2
-D 3
-U 2
-D 1
-L 2
-R 1
-L 1
-R 1
-D 3
-L 2
-D 3
-U 2
-L 2
-R 1
-L 2
-D 2
-R 1
-U 3
-D 2
-L 1
-D 2
-U 1
-L 1
-R 1
-D 3
-U 2
-L 4
-U 3
-R 4
-L 2
-D 4
-R 3
-D 4
-U 3
-R 3
-D 3
-R 1
-U 1
-D 2
-U 4
-D 4
-U 3
-D 1
-L 4
-R 2
-L 2
-U 3
-R 2
-D 1
-R 3
-D 1
-L 3
-R 2
-D 1
-L 3
-U 3
-R 4
-U 1
-L 2
-D 3
-R 2
-U 2
-D 4
-U 4
-D 4
-R 3
-D 1
-U 4
-D 3
-R 4
-U 1
-D 2
-L 5
-R 3
-U 1
-L 1
-D 4
-R 3
-U 1
-R 3
-D 5
-R 5
-D 2
-U 2
-L 5
-R 2
-U 1
-L 4
-R 4
-U 3
-D 3
-U 1
-R 4
-U 4
-R 4
-U 3
-D 1
-L 1
-U 2
-R 3
-L 5
-R 3
-D 1
-R 2
-U 1
-L 2
-D 5
-L 2
-D 2
-U 4
-D 2
-R 3
-L 5
-U 4
-D 1
-L 3
-D 4
-L 1
-D 4
-R 2
-D 2
-R 3
-U 2
-D 1
-R 1
-D 4
-R 1
-D 5
-U 4
-D 4
-R 1
-U 2
-D 2
-L 2
-R 1
-L 1
-D 5
-R 4
-D 2
-U 3
-L 1
-U 3
-L 4
-D 5
-R 4
-U 3
-D 1
-U 4
-L 2
-D 5
-L 4
-R 4
-U 4
-L 3
-U 5
-L 1
-R 2
-L 1
-R 1
-L 4
-R 3
-D 3
-U 3
-L 3
-D 4
-R 1
-L 2
-D 4
-U 3
-L 5
-D 1
-L 2
-U 2
-R 2
-L 3
-R 4
-D 4
-U 3
-D 5
-L 3
-U 2
-L 2
-U 2
-R 4
-L 1
-R 6
-D 3
-R 6
-U 1
-L 3
-R 4
-U 3
-L 6
-R 1
-D 3
-R 4
-D 3
-L 1
-U 1
-D 3
-R 2
-L 4
-U 2
-L 2
-U 4
-D 3
-L 5
-D 4
-L 4
-R 2
-D 4
-L 2
-R 5
-U 3
-D 2
-L 1
-R 5
-D 1
-U 1
-R 6
-L 1
-U 4
-D 2
-R 4
-D 5
-R 3
-U 5
-D 1
-R 1
-D 1
-U 6
-R 3
-U 6
-L 3
-R 6
-D 1
-U 1
-D 4
-U 1
-R 1
-D 2
-U 5
-L 5
-U 1
-L 5
-R 5
-U 3
-R 5
-U 4
-L 4
-U 3
-D 5
-R 6
-L 1
-U 3
-R 6
-L 1
-U 6
-R 1
-L 1
-D 3
-L 5
-R 6
-U 2
-R 5
-U 5
-D 5
-R 3
-U 2
-L 6
-D 5
-L 4
-U 5
-D 3
-U 4
-R 3
-D 6
-U 4
-R 4
-U 5
-L 3
-U 5
-R 4
-L 4
-U 3
-L 1
-R 6
-L 4
-U 6
-D 1
-R 4
-U 4
-L 5
-U 3
-D 6
-L 3
-U 1
-L 3
-R 5
-L 5
-R 7
-D 3
-L 4
-R 7
-U 1
-D 2
-R 7
-U 6
-L 2
-D 7
-L 4
-U 4
-L 7
-D 4
-R 7
-D 1
-U 7
-R 4
-D 7
-U 4
-D 4
-R 3
-L 4
-U 3
-L 6
-D 4
-U 1
-R 1
-U 1
-L 7
-D 1
-U 3
-L 3
-R 5
-U 6
-R 5
-U 5
-L 1
-U 5
-R 7
-D 1
-L 6
-R 2
-L 5
-U 3
-D 2
-U 1
-L 2
-R 7
-U 3
-D 6
-U 3
-D 7
-U 2
-D 1
-U 5
-D 2
-L 2
-U 5
-R 1
-D 5
-U 6
-D 5
-U 5
-L 1
-R 7
-L 4
-U 3
-L 7
-R 2
-L 7
-R 5
-D 3
-L 5
-R 2
-U 6
-L 3
-D 6
-U 5
-L 1
-D 7
-U 3
-L 2
-U 7
-D 7
-R 2
-L 1
-U 7
-L 1
-U 3
-R 6
-D 6
-R 6
-D 7
-L 5
-D 4
-U 4
-D 5
-R 5
-D 2
-R 7
-L 4
-U 7
-R 2
-U 3
-L 2
-R 6
-U 8
-R 6
-D 6
-L 7
-D 6
-L 1
-D 4
-U 1
-D 8
-U 8
-L 5
-R 8
-D 1
-R 6
-U 5
-R 3
-L 4
-D 5
-L 2
-D 8
-U 3
-L 7
-U 4
-D 8
-R 6
-U 4
-L 7
-D 8
-L 3
-U 7
-R 1
-U 2
-R 5
-D 2
-R 2
-U 6
-D 1
-R 6
-L 8
-U 5
-R 7
-U 1
-R 5
-L 7
-D 4
-L 7
-U 8
-L 8
-R 4
-L 1
-D 6
-L 6
-U 7
-L 5
-D 1
-U 3
-R 8
-D 2
-R 3
-L 7
-U 5
-R 6
-L 2
-D 2
-R 5
-L 4
-D 1
-U 4
-L 4
-R 7
-U 6
-D 7
-R 1
-L 8
-D 3
-L 7
-U 3
-R 3
-U 1
-D 7
-R 7
-L 7
-U 7
-L 5
-D 1
-R 8
-U 3
-D 1
-U 6
-R 7
-D 6
-L 3
-D 5
-R 8
-L 1
-U 3
-R 2
-U 1
-R 2
-D 5
-R 4
-L 1
-D 4
-U 5
-R 8
-U 2
-L 4
-R 6
-D 1
-U 7
-D 8
-R 1
-L 1
-U 4
-L 2
-U 7
-D 2
-R 7
-D 9
-U 7
-L 1
-D 8
-R 3
-L 1
-R 3
-U 5
-L 5
-D 7
-U 3
-R 4
-U 5
-L 2
-R 2
-D 7
-R 2
-U 4
-L 9
-R 7
-U 6
-R 4
-U 8
-L 2
-R 7
-U 5
-L 6
-R 7
-U 7
-L 4
-D 2
-L 1
-D 4
-R 5
-U 2
-L 3
-R 9
-U 8
-D 5
-L 3
-U 1
-L 2
-D 5
-L 7
-D 7
-U 1
-L 2
-D 8
-R 9
-L 5
-D 8
-U 1
-L 7
-U 9
-R 1
-L 1
-D 3
-R 3
-U 6
-L 4
-R 6
-L 1
-R 6
-U 2
-R 9
-D 3
-R 6
-U 5
-R 9
-L 7
-D 6
-L 9
-D 8
-R 4
-L 3
-U 2
-R 7
-L 5
-D 7
-U 8
-R 5
-L 2
-D 8
-L 4
-R 3
-D 1
-U 3
-D 3
-U 3
-D 3
-R 2
-L 5
-R 9
-L 5
-U 9
-R 3
-D 9
-L 4
-U 3
-L 2
-R 1
-D 1
-R 6
-D 4
-L 6
-R 1
-D 8
-L 3
-R 2
-D 7
-U 2
-L 4
-R 10
-L 9
-R 4
-L 2
-U 10
-R 5
-D 4
-L 7
-U 5
-L 7
-U 7
-D 6
-U 7
-R 5
-L 5
-D 5
-R 10
-D 4
-L 3
-D 4
-U 8
-L 5
-U 5
-L 8
-R 3
-U 5
-D 6
-L 2
-R 1
-L 7
-U 8
-R 2
-L 5
-R 1
-D 2
-U 1
-L 1
-R 5
-U 6
-D 6
-R 2
-U 8
-L 1
-D 8
-L 5
-R 10
-U 7
-R 9
-D 4
-L 4
-U 6
-D 3
-R 3
-L 5
-R 3
-U 6
-R 10
-U 5
-L 6
-R 2
-D 9
-U 2
-D 4
-U 3
-D 2
-U 7
-L 1
-R 3
-L 10
-U 7
-R 7
-U 2
-R 5
-L 3
-D 4
-R 1
-D 3
-L 3
-R 6
-D 6
-R 3
-L 9
-R 3
-L 1
-D 8
-R 9
-U 2
-D 6
-U 2
-L 9
-U 8
-D 2
-L 1
-U 2
-D 10
-U 7
-R 8
-D 4
-L 7
-U 1
-D 1
-U 1
-L 5
-D 5
-L 10
-R 9
-U 5
-L 8
-D 2
-R 2
-L 8
-D 8
-L 11
-U 5
-L 11
-D 9
-L 10
-U 1
-D 9
-R 5
-U 4
-D 2
-R 3
-L 7
-D 7
-L 9
-R 3
-L 3
-U 7
-R 1
-U 4
-R 7
-D 10
-L 8
-U 2
-L 6
-R 1
-U 10
-D 3
-U 2
-L 4
-D 5
-R 6
-L 7
-R 8
-L 4
-U 1
-D 11
-U 5
-R 4
-L 9
-R 8
-L 2
-R 4
-U 10
-D 4
-R 7
-D 3
-U 6
-D 6
-U 3
-L 11
-R 4
-D 6
-U 9
-R 10
-D 8
-U 3
-R 2
-U 7
-D 5
-R 10
-U 5
-R 3
-U 9
-L 2
-D 3
-U 1
-L 10
-U 2
-L 3
-U 10
-L 6
-D 4
-L 1
-D 1
-U 3
-L 10
-R 6
-L 1
-U 2
-R 1
-L 9
-D 4
-L 11
-U 6
-D 3
-L 9
-U 7
-D 3
-U 3
-R 8
-U 11
-R 2
-D 1
-U 3
-R 2
-L 7
-D 5
-R 10
-U 1
-D 10
-L 1
-U 10
-D 11
-U 5
-R 6
-D 6
-R 10
-L 9
-D 3
-U 4
-L 12
-D 7
-R 9
-U 4
-D 10
-L 11
-U 1
-R 3
-U 6
-L 11
-U 10
-R 3
-U 4
-L 9
-U 4
-L 12
-U 4
-D 2
-L 12
-D 12
-L 5
-U 9
-R 7
-D 12
-L 2
-U 3
-R 7
-D 9
-L 2
-R 9
-D 10
-U 6
-R 10
-D 8
-R 8
-L 9
-U 11
-R 5
-D 7
-U 3
-R 7
-U 10
-R 5
-D 4
-U 3
-D 6
-R 11
-U 9
-L 3
-D 5
-R 6
-L 11
-D 5
-R 2
-D 2
-R 8
-D 1
-U 5
-L 3
-U 8
-L 10
-D 7
-R 12
-D 7
-L 8
-D 5
-U 9
-D 10
-L 4
-R 6
-L 9
-R 10
-L 11
-U 8
-D 8
-R 2
-L 5
-U 9
-R 1
-L 12
-D 6
-R 11
-L 4
-R 6
-U 12
-D 5
-U 4
-L 7
-D 2
-R 5
-U 5
-D 7
-R 10
-L 9
-U 4
-D 11
-U 12
-D 6
-U 4
-L 3
-U 4
-R 11
-D 7
-L 11
-R 9
-U 2
-R 7
-D 5
-U 13
-L 10
-U 9
-L 9
-U 4
-D 8
-R 7
-L 8
-U 3
-L 1
-U 6
-D 11
-U 11
-D 12
-L 13
-U 13
-R 8
-D 4
-U 6
-D 3
-U 9
-R 11
-D 6
-R 9
-D 1
-U 8
-D 9
-R 2
-U 3
-R 13
-D 7
-U 6
-D 10
-U 13
-D 4
-U 1
-R 10
-D 9
-U 8
-D 7
-U 7
-L 13
-R 10
-L 8
-D 7
-U 9
-R 8
-L 8
-U 3
-D 5
-L 1
-U 5
-D 9
-R 2
-D 8
-L 7
-U 2
-R 11
-U 9
-D 7
-L 2
-D 9
-R 8
-L 12
-R 4
-D 7
-U 2
-L 8
-U 12
-D 11
-R 9
-U 9
-R 13
-U 3
-L 9
-U 9
-R 5
-U 10
-R 13
-U 6
-D 4
-U 2
-R 7
-D 3
-R 1
-L 4
-R 9
-L 3
-D 6
-L 9
-R 6
-D 1
-L 6
-D 5
-U 10
-R 2
-L 1
-R 1
-L 1
-U 9
-D 5
-U 2
-D 12
-L 9
-U 1
-L 14
-U 9
-L 11
-R 11
-L 3
-D 5
-U 5
-D 8
-L 9
-U 12
-R 11
-L 1
-D 9
-R 9
-L 7
-D 7
-U 4
-D 5
-L 11
-D 9
-R 13
-D 3
-R 9
-D 6
-U 12
-L 2
-D 10
-L 6
-U 12
-R 2
-D 9
-R 2
-D 12
-R 14
-U 3
-R 3
-D 12
-R 13
-U 1
-D 11
-L 1
-D 9
-U 11
-R 9
-D 10
-L 7
-D 13
-R 10
-D 2
-U 7
-L 7
-R 14
-D 10
-R 9
-D 13
-L 7
-U 5
-R 4
-D 4
-R 3
-U 8
-D 8
-R 4
-U 14
-R 8
-U 12
-D 6
-L 11
-D 12
-U 13
-L 13
-U 8
-L 2
-R 7
-U 1
-L 14
-R 1
-D 1
-L 9
-U 1
-R 8
-U 7
-D 13
-U 1
-D 5
-L 8
-R 13
-L 1
-U 14
-L 11
-D 7
-R 7
-L 8
-U 8
-D 9
-U 14
-L 1
-U 7
-D 7
-L 12
-D 7
-L 4
-U 14
-R 2
-D 3
-U 9
-L 9
-D 13
-U 9
-D 5
-U 11
-D 6
-U 10
-L 10
-R 8
-D 11
-L 3
-R 6
-D 10
-R 11
-L 3
-D 15
-R 6
-D 12
-U 4
-R 5
-L 15
-D 9
-L 5
-U 12
-R 2
-D 14
-U 10
-L 1
-U 14
-L 5
-D 12
-U 11
-D 6
-L 12
-R 2
-D 9
-L 15
-D 6
-L 3
-U 6
-L 8
-R 6
-L 11
-U 12
-L 2
-R 12
-U 15
-D 15
-U 1
-L 4
-R 3
-D 9
-U 9
-D 2
-U 2
-R 6
-U 1
-L 4
-R 5
-L 8
-R 2
-U 6
-D 13
-R 12
-L 13
-D 3
-L 9
-U 5
-R 5
-D 3
-R 8
-L 7
-R 12
-L 11
-U 9
-L 4
-D 9
-U 13
-R 15
-D 13
-U 12
-R 1
-L 14
-R 5
-L 8
-U 6
-D 5
-U 6
-D 7
-U 14
-D 4
-R 6
-L 7
-U 4
-L 12
-D 7
-U 11
-L 3
-R 4
-U 4
-L 9
-U 10
-R 9
-D 15
-R 9
-D 6
-L 4
-U 2
-L 5
-R 9
-L 8
-R 2
-D 5
-U 8
-D 11
-L 10
-R 1
-U 10
-L 10
-U 8
-L 8
-R 16
-U 9
-D 5
-L 8
-D 3
-L 5
-R 3
-U 7
-R 9
-U 3
-D 5
-U 11
-R 5
-L 7
-D 3
-R 15
-U 8
-R 12
-U 1
-D 1
-R 12
-L 15
-D 5
-R 5
-U 12
-R 11
-L 7
-U 13
-L 9
-R 14
-U 13
-D 15
-R 15
-D 9
-U 3
-L 15
-D 15
-U 8
-L 2
-U 4
-D 5
-L 10
-R 5
-D 15
-U 15
-L 11
-U 7
-L 3
-D 6
-L 9
-D 9
-U 11
-L 9
-U 2
-R 14
-L 13
-U 8
-D 13
-R 5
-U 5
-L 5
-U 15
-D 8
-U 15
-R 3
-U 12
-L 3
-D 9
-U 12
-D 10
-R 6
-L 16
-D 14
-L 10
-R 11
-U 9
-R 3
-U 15
-L 10
-U 14
-L 1
-D 13
-R 4
-U 8
-R 10
-D 2
-U 1
-L 2
-D 9
-R 10
-L 15
-D 7
-R 5
-D 12
-L 1
-R 8
-D 14
-U 4
-D 11
-R 12
-L 3
-D 6
-U 6
-D 12
-L 15
-U 4
-L 3
-R 10
-L 9
-D 5
-R 17
-U 12
-R 2
-L 2
-D 15
-U 4
-R 4
-L 17
-R 8
-U 12
-R 1
-L 3
-D 1
-R 7
-L 13
-U 14
-D 1
-U 5
-L 2
-R 5
-L 9
-U 6
-D 5
-R 14
-U 11
-D 7
-L 10
-D 10
-L 17
-R 2
-U 9
-D 7
-L 15
-R 8
-D 16
-L 15
-D 8
-L 4
-U 4
-D 5
-R 4
-D 15
-L 8
-D 11
-L 5
-U 5
-D 4
-R 14
-U 16
-R 8
-L 2
-R 16
-L 5
-U 15
-D 13
-L 5
-U 5
-D 6
-R 1
-L 4
-R 4
-U 6
-D 11
-L 8
-R 9
-U 8
-R 10
-U 1
-L 13
-R 10
-L 8
-D 11
-U 9
-L 7
-U 5
-L 15
-D 5
-R 3
-D 16
-L 2
-U 12
-R 15
-U 9
-L 13
-U 15
-L 4
-U 3
-R 3
-L 13
-D 5
-U 16
-R 5
-D 5
-U 9
-L 7
-U 10
-D 1
-R 2
-U 14
-L 13
-U 1
-L 12
-U 10
-D 3
-U 1
-R 14
-D 14
-U 16
-R 6
-U 14
-D 5
-L 18
-D 18
-U 8
-L 2
-R 5
-D 17
-U 18
-R 2
-L 10
-D 6
-R 17
-L 10
-U 1
-R 8
-L 14
-U 10
-R 7
-L 16
-U 9
-D 13
-U 2
-L 1
-D 14
-U 15
-R 15
-D 2
-R 13
-D 3
-R 9
-D 18
-R 16
-L 1
-R 3
-U 1
-R 1
-U 16
-D 17
-U 1
-D 11
-R 11
-D 1
-U 5
-R 1
-L 1
-R 10
-D 5
-R 1
-D 15
-R 14
-L 5
-U 12
-L 8
-R 6
-D 8
-L 5
-D 11
-L 14
-D 5
-R 15
-L 16
-R 12
-L 10
-D 8
-L 3
-R 1
-D 10
-U 13
-D 7
-U 10
-L 13
-D 8
-R 7
-L 13
-R 4
-L 9
-U 6
-D 14
-L 16
-D 18
-R 13
-U 13
-L 17
-R 5
-U 9
-R 11
-D 18
-R 16
-L 4
-D 8
-R 18
-L 14
-R 11
-D 14
-R 2
-D 6
-L 12
-D 3
-U 3
-L 4
-D 7
-L 4
-D 6
-L 12
-D 9
-L 2
-R 16
-L 12
-U 4
-L 12
-U 4
-R 17
-L 8
-U 8
-R 11
-U 14
-D 15
-L 19
-R 1
-L 16
-R 3
-U 15
-R 5
-L 1
-D 17
-L 13
-U 14
-D 9
-U 3
-L 7
-R 11
-D 7
-R 15
-L 6
-U 17
-R 17
-D 17
-U 18
-L 1
-R 8
-L 7
-D 9
-L 5
-D 1
-R 2
-D 16
-U 18
-D 7
-U 13
-L 10
-U 15
-R 18
-U 7
-L 9
-R 6
-U 18
-R 9
-D 8
-L 6
-R 7
-L 11
-U 12
-R 7
-D 4
-U 8
-R 16
-U 17
-D 4
-U 10
-L 19
-D 14
-L 12
-R 18
-D 4
-R 9
-U 12
-R 14
-U 9
-L 16
-D 14
-U 7
-R 14
-L 19
-U 16
-L 18
-U 1
-R 19
-D 7
-R 6
-U 12
-L 16
-R 16
-U 16
-L 6
-R 18
-L 19
-U 10
-D 15
-U 7
-D 1
-U 7
-L 12
-U 4
-R 5
-U 12
-R 18
-D 15
-U 4
-L 8
-U 18
-D 18
-L 3
-R 19
-L 15
-R 11
-U 1
-R 6
-L 8
-R 1
-L 3
-"#;
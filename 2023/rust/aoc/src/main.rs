@@ -0,0 +1,12 @@
+fn main() -> std::process::ExitCode {
+    aoc::main(&aoc::Workspace {
+        year: 2023,
+        root: concat!(env!("CARGO_MANIFEST_DIR"), "/.."),
+        solutions: &[
+            day1::SOLUTION,
+            day2::SOLUTION,
+            day3::SOLUTION,
+            day4::SOLUTION,
+        ],
+    })
+}
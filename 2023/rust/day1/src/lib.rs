@@ -0,0 +1,134 @@
+use aoc::ParseError;
+use eyre::eyre;
+
+/// The lines of the calibration document, letters and digits only.
+fn decode_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|line| match line.find(|c: char| !c.is_ascii_alphanumeric()) {
+            Some(i) => {
+                let c = line[i..].chars().next().unwrap_or_default();
+                let found = &line[i..i + c.len_utf8()];
+                Err(ParseError::at(input, found, "a letter or digit"))
+            }
+            None => Ok(line),
+        })
+        .collect()
+}
+
+fn part1(lines: &[&str]) -> eyre::Result<usize> {
+    lines.iter().try_fold(0_usize, |acc, line| -> eyre::Result<usize> {
+        let mut digits = line.as_bytes().iter().filter(|c| c.is_ascii_digit());
+        let left = digits.clone().next().ok_or_else(|| eyre!("No valid digit"))?;
+        let right = digits.next_back().ok_or_else(|| eyre!("No valid digit"))?;
+        Ok(acc + std::str::from_utf8(&[*left, *right])?.parse::<usize>()?)
+    })
+}
+
+fn match_digit(s: &[u8]) -> Option<u8> {
+    if s.is_empty() {
+        return None;
+    } 
+    if s[0].is_ascii_digit() {
+        return Some(s[0]);
+    }
+    let s = std::str::from_utf8(s).ok()?;
+    if s.starts_with("one") {
+        Some(49)
+    } else if s.starts_with("two") {
+        Some(50)
+    } else if s.starts_with("three") {
+        Some(51)
+    } else if s.starts_with("four") {
+        Some(52)
+    } else if s.starts_with("five") {
+        Some(53)
+    } else if s.starts_with("six") {
+        Some(54)
+    } else if s.starts_with("seven") {
+        Some(55)
+    } else if s.starts_with("eight") {
+        Some(56)
+    } else if s.starts_with("nine") {
+        Some(57)
+    } else {
+        None
+    }
+}
+
+fn part2(lines: &[&str]) -> eyre::Result<usize> {
+    lines.iter().try_fold(0_usize, |acc, line| -> eyre::Result<usize> {
+        // find first digit        
+        let mut first = 0;
+        for index in 0..line.len() {
+            if let Some(digit) = match_digit(&line.as_bytes()[index..]) {
+                first = digit;
+                break;
+            }
+        } 
+        let mut last = 0;
+        for index in (0..line.len()).rev() {            
+            if let Some(digit) = match_digit(&line.as_bytes()[index..]) {
+                last = digit;
+                break;
+            }
+        } 
+        assert!(first != 0 && last != 0);
+        Ok(acc + std::str::from_utf8(&[first, last])?.parse::<usize>()?)
+    })
+}
+
+struct Day1;
+
+impl aoc::Puzzle for Day1 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        part2(input)
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day1>(2023, 1, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use super::decode_input;
+
+    #[test]
+    fn test_decode_input() {
+        assert_eq!(decode_input("1abc2\n\nx7\n").unwrap(), ["1abc2", "x7"]);
+        assert_eq!(
+            decode_input("1abc2\nx-7\n").unwrap_err().to_string(),
+            "line 2, column 2: expected a letter or digit, found `-`"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(super::part1(&decode_input("1abc2
+        pqr3stu8vwx
+        a1b2c3d4e5f
+        treb7uchet").unwrap()).unwrap(), 142);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(super::part2(&decode_input("two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen").unwrap()).unwrap(), 281);
+    }
+
+    aoc::example_tests!(super::Day1, 1, 2);
+}
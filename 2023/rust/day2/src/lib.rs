@@ -0,0 +1,146 @@
+use aoc::{parse, ParseError};
+
+/// The cubes shown at once.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Cubes {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct Game {
+    id: usize,
+    rounds: Vec<Cubes>,
+}
+
+fn decode_cubes(input: &str, round: &str) -> Result<Cubes, ParseError> {
+    let mut cubes = Cubes::default();
+    for set in round.split(',') {
+        let (value, color) = parse::split_once(input, set.trim(), " ")?;
+        let value = parse::number(input, value)?;
+        match color {
+            "red" => cubes.red = value,
+            "green" => cubes.green = value,
+            "blue" => cubes.blue = value,
+            _ => return Err(ParseError::at(input, color, "`red`, `green` or `blue`")),
+        }
+    }
+    Ok(cubes)
+}
+
+fn decode_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (id, rounds) = parse::split_once(input, line, ": ")?;
+            Ok(Game {
+                id: parse::number(input, parse::strip_prefix(input, id, "Game ")?)?,
+                rounds: rounds
+                    .split(';')
+                    .map(|round| decode_cubes(input, round))
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+fn part1(games: &[Game]) -> usize {
+    games
+        .iter()
+        .filter(|game| {
+            game.rounds
+                .iter()
+                .all(|cubes| cubes.red <= 12 && cubes.green <= 13 && cubes.blue <= 14)
+        })
+        .map(|game| game.id)
+        .sum()
+}
+
+fn part2(games: &[Game]) -> usize {
+    games
+        .iter()
+        .map(|game| {
+            let fewest = game
+                .rounds
+                .iter()
+                .fold(Cubes::default(), |acc, cubes| Cubes {
+                    red: acc.red.max(cubes.red),
+                    green: acc.green.max(cubes.green),
+                    blue: acc.blue.max(cubes.blue),
+                });
+            fewest.red * fewest.green * fewest.blue
+        })
+        .sum()
+}
+
+struct Day2;
+
+impl aoc::Puzzle for Day2 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day2>(2023, 2, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_input, Cubes, Game};
+
+    #[test]
+    fn test_decode_input() {
+        assert_eq!(
+            decode_input("Game 7: 3 blue, 4 red; 2 green\n").unwrap(),
+            [Game {
+                id: 7,
+                rounds: vec![
+                    Cubes {
+                        red: 4,
+                        green: 0,
+                        blue: 3
+                    },
+                    Cubes {
+                        red: 0,
+                        green: 2,
+                        blue: 0
+                    }
+                ]
+            }]
+        );
+        assert_eq!(
+            decode_input("Game 7: 3 pink\n").unwrap_err().to_string(),
+            "line 1, column 11: expected `red`, `green` or `blue`, found `pink`"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(super::part1(&decode_input(TEST_INPUT).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(super::part2(&decode_input(TEST_INPUT).unwrap()), 2286);
+    }
+
+    const TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+    Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+    Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+    Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    aoc::example_tests!(super::Day2, 1, 2);
+}
@@ -0,0 +1,172 @@
+use aoc::{parse, ParseError};
+
+/// A number of the schematic, spanning the columns `start..=end` of its row.
+#[derive(Debug, PartialEq)]
+struct Number {
+    row: usize,
+    start: usize,
+    end: usize,
+    value: usize,
+}
+
+/// Anything but a digit or `.` in the schematic.
+#[derive(Debug, PartialEq)]
+struct Symbol {
+    row: usize,
+    col: usize,
+    symbol: char,
+}
+
+#[derive(Debug, Default)]
+struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+}
+
+impl Number {
+    /// Whether `symbol` touches the number, diagonals included.
+    fn adjacent(&self, symbol: &Symbol) -> bool {
+        symbol.row.abs_diff(self.row) <= 1
+            && symbol.col + 1 >= self.start
+            && symbol.col <= self.end + 1
+    }
+}
+
+fn decode_input(input: &str) -> Result<Schematic, ParseError> {
+    let mut schematic = Schematic::default();
+    for (row, line) in input.lines().enumerate() {
+        let mut digits: Option<usize> = None;
+        for (col, c) in line.char_indices().chain([(line.len(), '.')]) {
+            if c.is_ascii_digit() {
+                digits.get_or_insert(col);
+                continue;
+            }
+            if let Some(start) = digits.take() {
+                schematic.numbers.push(Number {
+                    row,
+                    start,
+                    end: col - 1,
+                    value: parse::number(input, &line[start..col])?,
+                });
+            }
+            if !c.is_ascii_graphic() {
+                let found = &line[col..col + c.len_utf8()];
+                return Err(ParseError::at(input, found, "a digit, `.` or a symbol"));
+            }
+            if c != '.' {
+                schematic.symbols.push(Symbol {
+                    row,
+                    col,
+                    symbol: c,
+                });
+            }
+        }
+    }
+    Ok(schematic)
+}
+
+fn part1(schematic: &Schematic) -> usize {
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| schematic.symbols.iter().any(|s| number.adjacent(s)))
+        .map(|number| number.value)
+        .sum()
+}
+
+fn part2(schematic: &Schematic) -> usize {
+    schematic
+        .symbols
+        .iter()
+        .filter(|s| s.symbol == '*')
+        .filter_map(|gear| {
+            let mut parts = schematic.numbers.iter().filter(|n| n.adjacent(gear));
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(a), Some(b), None) => Some(a.value * b.value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+struct Day3;
+
+impl aoc::Puzzle for Day3 {
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day3>(2023, 3, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_input, Number, Symbol};
+
+    #[test]
+    fn test_decode_input() {
+        let schematic = decode_input("..12\n#..3\n").unwrap();
+        assert_eq!(
+            schematic.numbers,
+            [
+                Number {
+                    row: 0,
+                    start: 2,
+                    end: 3,
+                    value: 12
+                },
+                Number {
+                    row: 1,
+                    start: 3,
+                    end: 3,
+                    value: 3
+                }
+            ]
+        );
+        assert_eq!(
+            schematic.symbols,
+            [Symbol {
+                row: 1,
+                col: 0,
+                symbol: '#'
+            }]
+        );
+        assert_eq!(
+            decode_input("..12\n.. 3\n").unwrap_err().to_string(),
+            "line 2, column 3: expected a digit, `.` or a symbol, found ` `"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(super::part1(&decode_input(TEST_INPUT).unwrap()), 4361);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(super::part2(&decode_input(TEST_INPUT).unwrap()), 467835);
+    }
+
+    const TEST_INPUT: &str = "
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+}
@@ -0,0 +1,107 @@
+use aoc::{parse, ParseError};
+
+#[derive(Debug, PartialEq)]
+struct Card {
+    winning: Vec<u32>,
+    yours: Vec<u32>,
+}
+
+fn decode_numbers(input: &str, numbers: &str) -> Result<Vec<u32>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|n| parse::number(input, n))
+        .collect()
+}
+
+fn decode_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (_, numbers) = parse::split_once(input, line, ":")?;
+            let (winning, yours) = parse::split_once(input, numbers, "|")?;
+            Ok(Card {
+                winning: decode_numbers(input, winning)?,
+                yours: decode_numbers(input, yours)?,
+            })
+        })
+        .collect()
+}
+
+fn part1(cards: &[Card]) -> usize {
+    cards
+        .iter()
+        .map(|card| {
+            match card
+                .winning
+                .iter()
+                .filter(|win| card.yours.contains(win))
+                .count()
+            {
+                0 => 0,
+                cnt => 2_usize.pow((cnt - 1) as u32),
+            }
+        })
+        .sum()
+}
+
+// fn part2(cards: &[Card]) -> usize {
+//     todo!()
+// }
+
+struct Day4;
+
+impl aoc::Puzzle for Day4 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day4>(2023, 4, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_input, Card};
+
+    #[test]
+    fn test_decode_input() {
+        assert_eq!(
+            decode_input("Card 1: 41 48 | 83  6 48\n").unwrap(),
+            [Card {
+                winning: vec![41, 48],
+                yours: vec![83, 6, 48]
+            }]
+        );
+        assert_eq!(
+            decode_input("Card 1: 41 48 83  6 48\n")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 8: expected `|`, found ` 41 48 83  6 48`"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(super::part1(&decode_input(TEST_INPUT).unwrap()), 13);
+    }
+
+    // #[test]
+    // fn test_part2() {
+    //     assert_eq!(super::part2(TEST_INPUT).unwrap(), 467835);
+    // }
+
+    const TEST_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    aoc::example_tests!(super::Day4, 1);
+}
@@ -0,0 +1,13 @@
+fn main() -> std::process::ExitCode {
+    aoc::main(&aoc::Workspace {
+        year: 2024,
+        root: concat!(env!("CARGO_MANIFEST_DIR"), "/.."),
+        solutions: &[
+            day1::SOLUTION,
+            day2::SOLUTION,
+            day14::SOLUTION,
+            day15::SOLUTION,
+            day22::SOLUTION,
+        ],
+    })
+}
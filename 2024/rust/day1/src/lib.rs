@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+fn parse_list(input: &str) -> (Vec<usize>, Vec<usize>) {
+    let (mut left, mut right) = input.lines().fold((vec![], vec![]), |mut acc, line| {
+        let mut list_iter = line.split_whitespace();
+        let left: usize = list_iter.next().unwrap().parse().unwrap();
+        acc.0.push(left);
+        let right: usize = list_iter.next().unwrap().parse().unwrap();
+        acc.1.push(right);
+        acc
+    });
+    left.sort();
+    right.sort();
+    (left, right)
+}
+
+fn part1((left, right): &(Vec<usize>, Vec<usize>)) -> usize {
+    left.iter()
+        .zip(right.iter())
+        .map(|(l, r)| l.abs_diff(*r))
+        .sum()
+}
+
+fn part2((left, right): &(Vec<usize>, Vec<usize>)) -> usize {
+    let right = right.iter().fold(HashMap::new(), |mut acc, next| {
+        acc.entry(*next)
+            .and_modify(|v| *v += *next)
+            .or_insert(*next);
+        acc
+    });
+    left.iter().map(|v| right.get(v).unwrap_or(&0_usize)).sum()
+}
+
+struct Day1;
+
+impl aoc::Puzzle for Day1 {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(parse_list(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day1>(2024, 1, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_part1() {
+        assert_eq!(super::part1(&super::parse_list(TEST_INPUT)), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(super::part2(&super::parse_list(TEST_INPUT)), 31);
+    }
+
+    const TEST_INPUT: &str = r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    aoc::example_tests!(super::Day1, 1);
+}
@@ -0,0 +1,276 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use aoc::{
+    combinator::{field, integer, lines, pair, parse_all, preceded, Parser},
+    image::Image,
+    num::crt,
+    ParseError,
+};
+use grid::{Grid, Pos};
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Robot {
+    p_x: usize,
+    p_y: usize,
+    v_x: isize,
+    v_y: isize,
+}
+
+/// A robot `p=<x>,<y> v=<x>,<y>`.
+fn robot<'a>() -> impl Parser<'a, Robot> {
+    let position = field("p", "=", pair(integer(), preceded(",", integer())));
+    let velocity = field("v", "=", pair(integer(), preceded(",", integer())));
+    pair(position, preceded(" ", velocity)).map(|((p_x, p_y), (v_x, v_y))| Robot {
+        p_x,
+        p_y,
+        v_x,
+        v_y,
+    })
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(robot(), s)
+    }
+}
+
+impl Robot {
+    fn update_pos(&mut self, seconds: usize, wide: usize, tall: usize) {
+        let r = self;
+        r.p_x = match r.v_x.cmp(&0) {
+            Ordering::Less => (r.p_x + (seconds * (wide - r.v_x.unsigned_abs()))) % wide,
+            Ordering::Greater => ((seconds * r.v_x as usize) + r.p_x) % wide,
+            Ordering::Equal => r.p_x,
+        };
+        r.p_y = match r.v_y.cmp(&0) {
+            Ordering::Less => (r.p_y + (seconds * (tall - r.v_y.unsigned_abs()))) % tall,
+            Ordering::Greater => (r.p_y + (seconds * r.v_y as usize)) % tall,
+            Ordering::Equal => r.p_y,
+        };
+    }
+}
+
+/// The number of robots on a tile.
+#[derive(Clone, Copy)]
+struct Tile(usize);
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            0 => write!(f, "."),
+            n => write!(f, "{n}"),
+        }
+    }
+}
+
+fn tiles(robots: &[Robot], width: usize, height: usize) -> Grid<Tile> {
+    let mut field = Grid::new(width, height, Tile(0));
+    robots
+        .iter()
+        .for_each(|r| field[Pos::new(r.p_y, r.p_x)].0 += 1);
+    field
+}
+
+fn get_quadrants(robots: &[Robot], width: usize, height: usize) -> [usize; 4] {
+    // sum quadrants
+    let middle_x = width / 2;
+    let middle_y = height / 2;
+    robots.iter().fold([0_usize, 0, 0, 0], |mut acc, r| {
+        match (r.p_x.cmp(&middle_x), r.p_y.cmp(&middle_y)) {
+            (Ordering::Less, Ordering::Less) => acc[0] += 1,
+            (Ordering::Greater, Ordering::Less) => acc[1] += 1,
+            (Ordering::Less, Ordering::Greater) => acc[2] += 1,
+            (Ordering::Greater, Ordering::Greater) => acc[3] += 1,
+            _ => {}
+        }
+        acc
+    })
+}
+
+fn decode_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse_all(lines(robot()), input)
+}
+
+fn get_robots(input: &[Robot], seconds: usize, width: usize, height: usize) -> Vec<Robot> {
+    input
+        .iter()
+        .map(|r| {
+            let mut r = r.clone();
+            r.update_pos(seconds, width, height);
+            r
+        })
+        .collect::<Vec<_>>()
+}
+
+fn part1(input: &[Robot], seconds: usize, width: usize, height: usize) -> usize {
+    let robots = get_robots(input, seconds, width, height);
+    let quadrants = get_quadrants(&robots, width, height);
+    quadrants.iter().product()
+}
+
+/// The spread of `values` scaled by their count squared to stay integral,
+/// in `u128` as the squares of large coordinates overflow `usize`.
+fn spread(values: impl Iterator<Item = usize>) -> u128 {
+    let (n, sum, squares) = values.fold((0_u128, 0_u128, 0_u128), |(n, sum, squares), v| {
+        let v = v as u128;
+        (n + 1, sum + v, squares + v * v)
+    });
+    n * squares - sum * sum
+}
+
+/// The second in `0..period` at which `coordinate` of the robots is least
+/// spread.
+fn tightest(
+    input: &[Robot],
+    period: usize,
+    (width, height): (usize, usize),
+    coordinate: impl Fn(&Robot) -> usize,
+) -> usize {
+    (0..period)
+        .min_by_key(|&i| spread(get_robots(input, i, width, height).iter().map(&coordinate)))
+        .expect("the period is positive")
+}
+
+fn part2(input: &[Robot], width: usize, height: usize) -> aoc::Result<usize> {
+    // the x coordinates repeat every `width` seconds and the y coordinates
+    // every `height` seconds, while watching the recording it seemed obvious
+    // that the robots line up in columns at one second and in rows at another
+    // of these periods, the tree is where both coincide
+    let x = tightest(input, width, (width, height), |r| r.p_x);
+    let y = tightest(input, height, (width, height), |r| r.p_y);
+    let (second, _) = crt([(x as i128, width as i128), (y as i128, height as i128)])?;
+    let second = usize::try_from(second)?;
+    if aoc::record::is_recording() {
+        for i in (x..=second).step_by(width) {
+            let robots = get_robots(input, i, width, height);
+            aoc::record::frame(&format!("{}{i}\n", tiles(&robots, width, height)));
+        }
+    }
+    Ok(second)
+}
+
+struct Day14;
+
+impl aoc::Puzzle for Day14 {
+    type Input<'a> = Vec<Robot>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input, 100, 101, 103))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        let part2 = part2(input, 101, 103)?;
+        // the easteregg
+        aoc::image::snapshot(|| {
+            let robots = get_robots(input, part2, 101, 103);
+            Image::from_grid(&tiles(&robots, 101, 103), |tile| match tile.0 {
+                0 => [10, 30, 10],
+                _ => [60, 220, 60],
+            })
+            .scaled(4)
+        });
+        Ok(part2)
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day14>(2024, 14, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use crate::Robot;
+
+    #[test]
+    fn test_parse_robot() {
+        assert_eq!(
+            "p=0,4 v=3,-3".parse::<Robot>().unwrap(),
+            Robot {
+                p_x: 0,
+                p_y: 4,
+                v_x: 3,
+                v_y: -3
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_robot_error() {
+        let err = "p=0,4 v=3;-3".parse::<Robot>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 10: expected `,`, found `;`"
+        );
+    }
+
+    #[test]
+    fn test_update_pos() {
+        let mut robot = "p=2,4 v=2,-3".parse::<Robot>().unwrap();
+        robot.update_pos(5, 11, 7);
+        assert_eq!(
+            robot,
+            Robot {
+                p_x: 1,
+                p_y: 3,
+                v_x: 2,
+                v_y: -3
+            }
+        );
+    }
+
+    #[test]
+    fn test_spread() {
+        assert_eq!(super::spread([1, 2, 3].into_iter()), 6);
+        // the squares overflow `usize`
+        let far = 1 << 40;
+        assert_eq!(super::spread([far, far + 2].into_iter()), 4);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            super::part1(&super::decode_input(TEST_INPUT).unwrap(), 100, 11, 7),
+            12
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        // the robots gather in a 3x2 block after 52 seconds
+        let (width, height, second) = (11, 7, 52);
+        let velocities = [(1, 2), (2, -1), (3, 3), (-1, 1), (-2, -3), (4, 2), (-3, -2)];
+        let robots: Vec<Robot> = velocities
+            .iter()
+            .enumerate()
+            .map(|(i, &(v_x, v_y))| {
+                let start = |target: usize, v: isize, size: usize| {
+                    (target as isize - v * second as isize).rem_euclid(size as isize) as usize
+                };
+                Robot {
+                    p_x: start(4 + i % 3, v_x, width),
+                    p_y: start(2 + i % 2, v_y, height),
+                    v_x,
+                    v_y,
+                }
+            })
+            .collect();
+        assert_eq!(super::part2(&robots, width, height).unwrap(), second);
+    }
+
+    const TEST_INPUT: &str = r"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+}
@@ -0,0 +1,343 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc::{parse, ParseError};
+use grid::{Grid, Pos};
+
+#[derive(Debug, Clone)]
+enum RobotMove {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Warehouse {
+    map: Grid<u8>,
+    robot_position: Pos,
+    robot_moves: Vec<RobotMove>,
+}
+
+/// The first character of `text`, a slice of `input`, that isn't one of
+/// `allowed` or whitespace.
+fn find_invalid(input: &str, text: &str, allowed: &str, expected: &str) -> Result<(), ParseError> {
+    match text
+        .char_indices()
+        .find(|&(_, c)| !c.is_whitespace() && !allowed.contains(c))
+    {
+        Some((i, c)) => Err(ParseError::at(input, &text[i..i + c.len_utf8()], expected)),
+        None => Ok(()),
+    }
+}
+
+impl FromStr for Warehouse {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (map_text, moves) = parse::split_once(s, s, "\n\n")?;
+        find_invalid(s, map_text, "#.O[]@", "`#`, `.`, `O`, `[`, `]` or `@`")?;
+        find_invalid(s, moves, "^v<>", "`^`, `v`, `<` or `>`")?;
+        let map: Grid<u8> = parse::grid(s, map_text, None)?;
+        let robot_position = map.position(|&c| c == b'@').ok_or_else(|| {
+            let first_line = map_text.lines().next().unwrap_or(map_text);
+            ParseError::at(s, first_line, "a robot `@`")
+        })?;
+        Ok(Warehouse {
+            map,
+            robot_position,
+            robot_moves: moves
+                .bytes()
+                .filter_map(|c| match c {
+                    b'^' => Some(RobotMove::Up),
+                    b'v' => Some(RobotMove::Down),
+                    b'<' => Some(RobotMove::Left),
+                    b'>' => Some(RobotMove::Right),
+                    _ => None,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Warehouse {
+    fn find_free_horizontal(&self, row: usize, direction: isize) -> Option<usize> {
+        let mut curr_pos = self.robot_position.col as isize;
+        // apply delta until we find a free space or a wall
+        loop {
+            curr_pos += direction;
+            let obstacle = self.map[Pos::new(row, curr_pos as usize)];
+            match obstacle {
+                b'#' => return None,
+                b'.' => return Some(curr_pos as usize),
+                b'O' | b'[' | b']' => continue,
+                c => {
+                    dbg!(&self);
+                    dbg!(c);
+                    unreachable!()
+                }
+            }
+        }
+    }
+
+    fn move_horizontal(&mut self, direction: isize) {
+        let row = self.robot_position.row;
+        if let Some(mut new_position) = self.find_free_horizontal(row, direction) {
+            // shift objects until we reach the robot
+            loop {
+                let next = (new_position as isize - direction) as usize;
+                self.map
+                    .swap(Pos::new(row, new_position), Pos::new(row, next));
+                if next == self.robot_position.col {
+                    self.robot_position.col = new_position;
+                    return;
+                }
+                new_position = next;
+            }
+        }
+    }
+
+    fn find_free_vertical(&self, pos: Pos, direction: isize) -> HashSet<Pos> {
+        let mut positions = HashSet::new();
+        let next = Pos::new((pos.row as isize + direction) as usize, pos.col);
+        match self.map.get(next) {
+            Some(b'O') => {
+                let free = self.find_free_vertical(next, direction);
+                if free.is_empty() {
+                    return HashSet::new();
+                }
+                positions.extend(free);
+                positions.insert(next);
+            }
+            Some(b']') => {
+                let new_left = self.find_free_vertical(Pos::new(next.row, next.col - 1), direction);
+                let new_right = self.find_free_vertical(next, direction);
+                if new_right.is_empty() || new_left.is_empty() {
+                    return HashSet::new();
+                }
+                positions.extend(new_left);
+                positions.extend(new_right);
+                positions.insert(next);
+            }
+            Some(b'[') => {
+                let new_left = self.find_free_vertical(next, direction);
+                let new_right =
+                    self.find_free_vertical(Pos::new(next.row, next.col + 1), direction);
+                if new_right.is_empty() || new_left.is_empty() {
+                    return HashSet::new();
+                }
+                positions.extend(new_left);
+                positions.extend(new_right);
+                positions.insert(next);
+            }
+            Some(b'.') => {
+                positions.insert(next);
+            }
+            Some(b'#') => { /* we hit the wall -> stop! */ }
+            c => {
+                dbg!(&self);
+                dbg!(c);
+                unreachable!()
+            }
+        }
+        positions
+    }
+
+    fn move_vertical(&mut self, direction: isize) {
+        let mut new_positions: Vec<Pos> = self
+            .find_free_vertical(self.robot_position, direction)
+            .into_iter()
+            .collect();
+        if !new_positions.is_empty() {
+            if direction.is_positive() {
+                new_positions.sort_by_key(|p| std::cmp::Reverse(p.row));
+            } else {
+                new_positions.sort_by_key(|p| p.row);
+            }
+            // now that all target locations have been found shift boxes and robot
+            // if no move is possible new_positions is empty
+            for new_pos in new_positions {
+                let old_pos = Pos::new((new_pos.row as isize - direction) as usize, new_pos.col);
+                self.map.swap(new_pos, old_pos);
+            }
+            // finally update position of robot, too!
+            self.robot_position.row = (self.robot_position.row as isize + direction) as usize;
+        }
+    }
+
+    fn exec_moves(&mut self) {
+        //dbg!(&self.map);
+        for i in 0..self.robot_moves.len() {
+            //dbg!(&self.robot_moves[i]);
+            match self.robot_moves[i] {
+                RobotMove::Down => self.move_vertical(1),
+                RobotMove::Up => self.move_vertical(-1),
+                RobotMove::Left => self.move_horizontal(-1),
+                RobotMove::Right => self.move_horizontal(1),
+            }
+            //dbg!(&self.map);
+        }
+    }
+
+    fn sum_boxes(&self, box_symbol: u8) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, &c)| c == box_symbol)
+            .map(|(pos, _)| 100 * pos.row + pos.col)
+            .sum()
+    }
+}
+
+fn transform_input(input: &str) -> String {
+    input
+        .replace("O", "[]")
+        .replace("#", "##")
+        .replace(".", "..")
+        .replace("@", "@.")
+}
+
+/// The warehouse of part 1 and the one twice as wide of part 2.
+fn decode_input(input: &str) -> Result<(Warehouse, Warehouse), ParseError> {
+    let warehouse = input.parse()?;
+    Ok((warehouse, transform_input(input).parse()?))
+}
+
+fn part1(warehouse: &Warehouse) -> usize {
+    let mut warehouse = warehouse.clone();
+    warehouse.exec_moves();
+    warehouse.sum_boxes(b'O')
+}
+
+fn part2(warehouse: &Warehouse) -> usize {
+    let mut warehouse = warehouse.clone();
+    warehouse.exec_moves();
+    warehouse.sum_boxes(b'[')
+}
+
+struct Day15;
+
+impl aoc::Puzzle for Day15 {
+    type Input<'a> = (Warehouse, Warehouse);
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(&input.0))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(&input.1))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day15>(2024, 15, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use super::decode_input;
+
+    #[test]
+    fn test_parse() {
+        let warehouse: super::Warehouse = TEST_INPUT.parse().unwrap();
+        dbg!(&warehouse);
+        assert_eq!(
+            "#@.\n\n<x"
+                .parse::<super::Warehouse>()
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 2: expected `^`, `v`, `<` or `>`, found `x`"
+        );
+        assert_eq!(
+            "#..\n\n<>"
+                .parse::<super::Warehouse>()
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 1: expected a robot `@`, found `#..`"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(super::part1(&decode_input(TEST_INPUT).unwrap().0), 2028);
+    }
+
+    #[test]
+    fn test_part1_large() {
+        assert_eq!(
+            super::part1(&decode_input(TEST_INPUT_LARGE).unwrap().0),
+            10092
+        );
+    }
+
+    #[test]
+    fn test_transform_input() {
+        assert_eq!(
+            super::transform_input(TEST_INPUT_LARGE),
+            EXPECTED_INPUT_PART2
+        );
+    }
+
+    #[test]
+    fn test_part2_large() {
+        assert_eq!(
+            super::part2(&decode_input(TEST_INPUT_LARGE).unwrap().1),
+            9021
+        );
+    }
+
+    const TEST_INPUT: &str = r"########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    const TEST_INPUT_LARGE: &str = r"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    const EXPECTED_INPUT_PART2: &str = r"####################
+##....[]....[]..[]##
+##............[]..##
+##..[][]....[]..[]##
+##....[]@.....[]..##
+##[]##....[]......##
+##[]....[]....[]..##
+##..[][]..[]..[][]##
+##........[]......##
+####################
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+}
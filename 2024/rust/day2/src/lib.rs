@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+
+use aoc::{parse, ParseError};
+
+/// The levels of every report, a report has at least one level.
+fn decode_input(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                return Err(ParseError::at(input, line, "a level"));
+            }
+            line.split_whitespace()
+                .map(|digits| parse::number(input, digits))
+                .collect()
+        })
+        .collect()
+}
+
+fn part1(reports: &[Vec<usize>]) -> usize {
+    reports.iter().map(|levels| check_levels(levels)).sum()
+}
+
+fn check_levels(levels: &[usize]) -> usize {
+    let mut prev = levels.first().unwrap();
+    let mut direction: Option<Ordering> = None;
+    for next in &levels[1..] {
+        let new_direction = next.cmp(prev);
+        if next.abs_diff(*prev) > 3
+            || direction.is_some_and(|d| new_direction == Ordering::Equal || d != new_direction)
+        {
+            return 0;
+        }
+        prev = next;
+        direction = Some(new_direction);
+    }
+    1
+}
+
+fn part2(reports: &[Vec<usize>]) -> usize {
+    reports
+        .iter()
+        .map(|levels| {
+            if chk_levels_graceful(levels) == 0 {
+                check_levels(&levels[1..])
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+fn chk_levels_graceful(levels: &[usize]) -> usize {
+    let mut prev = levels.first().unwrap();
+    let mut direction: Option<Ordering> = None;
+    let mut used_dampener = false;
+    for next in &levels[1..] {
+        let new_direction = next.cmp(prev);
+        if next.abs_diff(*prev) > 3
+            || direction
+                .as_ref()
+                .is_some_and(|d| new_direction == Ordering::Equal || *d != new_direction)
+        {
+            if used_dampener {
+                return 0;
+            } else {
+                used_dampener = true;
+                continue;
+            }
+        }
+        prev = next;
+        direction = Some(new_direction);
+    }
+    1
+}
+
+struct Day2;
+
+impl aoc::Puzzle for Day2 {
+    type Input<'a> = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day2>(2024, 2, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use super::decode_input;
+
+    fn part1(input: &str) -> usize {
+        super::part1(&decode_input(input).unwrap())
+    }
+
+    fn part2(input: &str) -> usize {
+        super::part2(&decode_input(input).unwrap())
+    }
+
+    #[test]
+    fn test_decode_input() {
+        assert_eq!(decode_input("7 6\n1\n").unwrap(), [vec![7, 6], vec![1]]);
+        assert_eq!(
+            decode_input("7 6\n\n1 2\n").unwrap_err().to_string(),
+            "line 2, column 1: expected a level, found the end of the input"
+        );
+        assert_eq!(
+            decode_input("7 -6\n").unwrap_err().to_string(),
+            "line 1, column 3: expected a number, found `-6`"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(TEST_INPUT), 2);
+        assert_eq!(part1(TEST_INPUT2), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(TEST_INPUT), 4);
+        assert_eq!(part2(TEST_INPUT2), 1);
+        assert_eq!(part2("1 4 3 5 6 7"), 1);
+        assert_eq!(part2("1 4 4 5 6 7"), 1);
+        assert_eq!(part2("6 1 4 5 6 7"), 1);
+        assert_eq!(part2("6 6 1 4 5 6 7"), 0);
+    }
+
+    const TEST_INPUT: &str = r"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    const TEST_INPUT2: &str = r"96 92 90 87 84";
+
+    aoc::example_tests!(super::Day2, 1, 2);
+}
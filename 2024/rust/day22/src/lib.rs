@@ -0,0 +1,15 @@
+struct Day22;
+
+impl aoc::Puzzle for Day22 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Err::<aoc::Answer, _>(aoc::puzzle::Unimplemented.into())
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day22>(2024, 22, aoc::default_input!());
@@ -0,0 +1,7 @@
+fn main() -> std::process::ExitCode {
+    aoc::main(&aoc::Workspace {
+        year: 2025,
+        root: concat!(env!("CARGO_MANIFEST_DIR"), "/.."),
+        solutions: &[day1::SOLUTION],
+    })
+}
@@ -0,0 +1,137 @@
+use aoc::{parse, ParseError};
+
+/// A rotation of the dial by a number of clicks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Rotation {
+    Left(isize),
+    Right(isize),
+}
+
+fn decode_input(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            if let Some(clicks) = line.strip_prefix('R') {
+                Ok(Rotation::Right(parse::number(input, clicks)?))
+            } else if let Some(clicks) = line.strip_prefix('L') {
+                Ok(Rotation::Left(parse::number(input, clicks)?))
+            } else {
+                let c = line.chars().next().unwrap_or_default();
+                Err(ParseError::at(input, &line[..c.len_utf8()], "`L` or `R`"))
+            }
+        })
+        .collect()
+}
+
+fn part1(rotations: &[Rotation]) -> isize {
+    rotations
+        .iter()
+        .fold((50, 0), |mut acc, rotation| {
+            match *rotation {
+                Rotation::Right(n) => acc.0 = (acc.0 + n) % 100,
+                Rotation::Left(n) => acc.0 = ((100 + acc.0) - (n % 100)) % 100,
+            }
+            if acc.0 == 0 {
+                acc.1 += 1;
+            }
+            acc
+        })
+        .1
+}
+
+fn part2(rotations: &[Rotation]) -> usize {
+    rotations
+        .iter()
+        .fold((50_isize, 0), |mut acc, rotation| {
+            match *rotation {
+                Rotation::Right(n) => {
+                    acc.1 += (n as usize + acc.0 as usize) / 100;
+                    acc.0 = (acc.0 + n) % 100;
+                }
+                Rotation::Left(n) => {
+                    acc.1 += n as usize / 100;
+                    let rest = n % 100;
+                    if rest > 0 {
+                        if rest >= acc.0 {
+                            if acc.0 != 0 {
+                                acc.1 += 1;
+                            }
+                            acc.0 = (100 + acc.0 - rest) % 100;
+                        } else {
+                            acc.0 -= rest;
+                        }
+                    }
+                }
+            }
+            acc
+        })
+        .1
+}
+
+struct Day1;
+
+impl aoc::Puzzle for Day1 {
+    type Input<'a> = Vec<Rotation>;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(decode_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+
+pub const SOLUTION: aoc::Solution = aoc::Solution::new::<Day1>(2025, 1, aoc::default_input!());
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_input, Rotation};
+
+    #[test]
+    fn test_decode_input() {
+        assert_eq!(
+            decode_input("L68\nR48\n").unwrap(),
+            [Rotation::Left(68), Rotation::Right(48)]
+        );
+        assert_eq!(
+            decode_input("L68\nU48\n").unwrap_err().to_string(),
+            "line 2, column 1: expected `L` or `R`, found `U`"
+        );
+        assert_eq!(
+            decode_input("L68\nR4x\n").unwrap_err().to_string(),
+            "line 2, column 2: expected a number, found `4x`"
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(super::part1(&decode_input(TEST_INPUT).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        // assert_eq!(super::part2(&decode_input("L50").unwrap()), 1);
+        // assert_eq!(super::part2(&decode_input("R1000").unwrap()), 10);
+        assert_eq!(super::part2(&decode_input(TEST_INPUT).unwrap()), 6);
+    }
+
+    const TEST_INPUT: &str = r"
+       L68
+       L30
+       R48
+       L5
+       R60
+       L55
+       L1
+       L99
+       R14
+       L82
+    ";
+}
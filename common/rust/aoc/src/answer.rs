@@ -0,0 +1,104 @@
+//! The answer of a puzzle part.
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// A numeric or textual puzzle answer.
+///
+/// Answers are compared by value, so an answer read back from a file
+/// equals the one computed by a part regardless of its integer type.
+/// Strings are parsed as well, a part returning `"42"` answers a number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Surrounding whitespace is ignored, everything that is not an integer
+    /// is a [`Answer::Text`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(s.parse()
+            .map_or_else(|_| Answer::Text(s.to_owned()), Answer::Number))
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Number(value.into())
+            }
+        })*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        let Ok(answer) = value.parse();
+        answer
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("42".parse(), Ok(Answer::Number(42)));
+        assert_eq!(" -17\n".parse(), Ok(Answer::Number(-17)));
+        assert_eq!("CMZ".parse(), Ok(Answer::Text("CMZ".into())));
+        assert_eq!("1,2".parse(), Ok(Answer::Text("1,2".into())));
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for answer in [
+            Answer::from(-3_i64),
+            Answer::from(u64::MAX),
+            "FZBPBFZF".into(),
+        ] {
+            assert_eq!(answer.to_string().parse(), Ok(answer));
+        }
+    }
+
+    #[test]
+    fn test_eq_across_types() {
+        assert_eq!(Answer::from(24_usize), Answer::from(24_i32));
+        assert_eq!(Answer::from(7_u8), "007".parse().unwrap());
+        assert_eq!(Answer::from(7_u8), Answer::from(String::from("7")));
+        assert_ne!(Answer::from(7_u8), Answer::from("7a"));
+    }
+}
@@ -0,0 +1,114 @@
+//! The file of known answers of a year workspace.
+//!
+//! Every line records the answer of one part as `<day> <part> <answer>`.
+//! Empty lines and lines starting with `#` are ignored:
+//!
+//! ```text
+//! # day part answer
+//! 1 1 70613
+//! 5 1 FRDSQRRCD
+//! ```
+
+use std::{collections::BTreeMap, fs, io, path::Path, str::FromStr};
+
+use crate::Answer;
+
+/// File name of the answers, relative to the workspace root.
+pub const FILE_NAME: &str = "answers.txt";
+
+/// The recorded answers keyed by day and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, u8), Answer>);
+
+impl Answers {
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    /// The answers ordered by day and part.
+    pub fn iter(&self) -> impl Iterator<Item = (&(u8, u8), &Answer)> {
+        self.0.iter()
+    }
+
+    /// Reads the answers from `path`, a missing file has no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|err| format!("{}:{err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+        for (line_no, line) in s.lines().enumerate().map(|(i, l)| (i + 1, l.trim())) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let (Some(day), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!("{line_no}: expected `<day> <part> <answer>`"));
+            };
+            let day = day
+                .parse()
+                .ok()
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| format!("{line_no}: invalid day `{day}`"))?;
+            let part = part
+                .parse()
+                .ok()
+                .filter(|p| (1..=2).contains(p))
+                .ok_or_else(|| format!("{line_no}: invalid part `{part}`"))?;
+            if answers.insert((day, part), answer.into()).is_some() {
+                return Err(format!(
+                    "{line_no}: duplicate answer of day {day} part {part}"
+                ));
+            }
+        }
+        Ok(Answers(answers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+    use crate::Answer;
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = "# day part answer\n\n1 1 70613\n5 2  HRFTQVWNN \n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Number(70613)));
+        assert_eq!(answers.get(5, 2), Some(&Answer::Text("HRFTQVWNN".into())));
+        assert_eq!(answers.get(1, 2), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "1 1 3\n1 1".parse::<Answers>(),
+            Err("2: expected `<day> <part> <answer>`".into())
+        );
+        assert_eq!(
+            "26 1 3".parse::<Answers>(),
+            Err("1: invalid day `26`".into())
+        );
+        assert_eq!(
+            "1 3 3".parse::<Answers>(),
+            Err("1: invalid part `3`".into())
+        );
+        assert_eq!(
+            "1 1 3\n1 1 4".parse::<Answers>(),
+            Err("2: duplicate answer of day 1 part 1".into())
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! The `bench` command: times the parts repeatedly and compares the timings
+//! to a stored baseline.
+//!
+//! The baseline is a plain text file in the workspace root, every line holds
+//! the timings of one part in nanoseconds:
+//!
+//! ```text
+//! # day part min median max
+//! 20 1 41520311 42110093 45022120
+//! ```
+
+use std::{collections::BTreeMap, fs, io, path::Path, str::FromStr, time::Duration};
+
+use crate::{
+    answers::{self, Answers},
+    cli::BenchArgs,
+    input,
+    verify::describe,
+    Outcome, Workspace,
+};
+
+/// File name of the baseline, relative to the workspace root.
+pub const BASELINE_FILE: &str = "bench_baseline.txt";
+
+/// Minimum, median and maximum time of the runs of a part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no timings.
+    pub fn new(mut timings: Vec<Duration>) -> Option<Self> {
+        timings.sort_unstable();
+        let len = timings.len();
+        let median = match len {
+            0 => return None,
+            _ if len.is_multiple_of(2) => (timings[len / 2 - 1] + timings[len / 2]) / 2,
+            _ => timings[len / 2],
+        };
+        Some(Stats {
+            min: timings[0],
+            median,
+            max: timings[len - 1],
+        })
+    }
+}
+
+/// The stored timings keyed by day and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline(BTreeMap<(u8, u8), Stats>);
+
+impl Baseline {
+    /// Reads the baseline from `path`, a missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|err| format!("{}:{err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baseline = BTreeMap::new();
+        for (line_no, line) in s.lines().enumerate().map(|(i, l)| (i + 1, l.trim())) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line
+                .split_whitespace()
+                .map(str::parse::<u64>)
+                .collect::<Result<Vec<_>, _>>();
+            let Ok(&[day, part, min, median, max]) = fields.as_deref() else {
+                return Err(format!(
+                    "{line_no}: expected `<day> <part> <min> <median> <max>`"
+                ));
+            };
+            let (Ok(day), Ok(part)) = (u8::try_from(day), u8::try_from(part)) else {
+                return Err(format!("{line_no}: invalid day or part"));
+            };
+            baseline.insert(
+                (day, part),
+                Stats {
+                    min: Duration::from_nanos(min),
+                    median: Duration::from_nanos(median),
+                    max: Duration::from_nanos(max),
+                },
+            );
+        }
+        Ok(Baseline(baseline))
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part min median max (ns)")?;
+        for ((day, part), stats) in &self.0 {
+            writeln!(
+                f,
+                "{day} {part} {} {} {}",
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change of the median compared to the baseline, in percent.
+fn change(current: &Stats, baseline: &Stats) -> f64 {
+    let baseline = baseline.median.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.median.as_secs_f64() - baseline) / baseline * 100.0
+}
+
+enum Timing {
+    Measured(Stats),
+    Failed(String),
+}
+
+struct Row {
+    day: u8,
+    part: u8,
+    timing: Timing,
+}
+
+/// Runs every part with a recorded answer `args.runs` times and prints the
+/// timings next to the change against the baseline.
+///
+/// Only parts with a recorded answer are benchmarked, like for `verify`, so
+/// unfinished days can't stall the run. Returns `false` if a part failed or
+/// its median regressed by more than `args.threshold` percent, the baseline
+/// is then only saved with `args.force`.
+pub fn bench(workspace: &Workspace, args: &BenchArgs) -> Result<bool, String> {
+    let root = Path::new(workspace.root);
+    let answers = Answers::load(&root.join(answers::FILE_NAME))?;
+    let baseline_path = root.join(BASELINE_FILE);
+    let mut baseline = Baseline::load(&baseline_path)?;
+    let mut rows = vec![];
+    for solution in workspace.solutions {
+        if args.day.is_some_and(|d| d != solution.day) {
+            continue;
+        }
+        let parts: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|&part| answers.get(solution.day, part).is_some())
+            .collect();
+        if parts.is_empty() {
+            continue;
+        }
+        let input = match input::load(solution, None) {
+            Ok(input) => input,
+            Err(err) => {
+                rows.extend(parts.iter().map(|&part| Row {
+                    day: solution.day,
+                    part,
+                    timing: Timing::Failed(err.to_string()),
+                }));
+                continue;
+            }
+        };
+        let mut timings: Vec<Result<Vec<Duration>, String>> = vec![Ok(vec![]); parts.len()];
+        for _ in 0..args.runs {
+            let run = solution.solve(&input, &parts);
+            let parts = match run.parts {
+                Ok(parts) => parts,
+                Err(outcome) => {
+                    let err = format!("input {}", describe(&outcome));
+                    timings.iter_mut().for_each(|t| *t = Err(err.clone()));
+                    break;
+                }
+            };
+            for (timing, part) in timings.iter_mut().zip(parts) {
+                if let Ok(durations) = timing {
+                    match part.outcome {
+                        Outcome::Solved(_) => durations.push(part.elapsed),
+                        outcome => *timing = Err(describe(&outcome)),
+                    }
+                }
+            }
+        }
+        rows.extend(parts.iter().zip(timings).map(|(&part, timing)| Row {
+            day: solution.day,
+            part,
+            timing: match timing.map(Stats::new) {
+                Ok(Some(stats)) => Timing::Measured(stats),
+                Ok(None) => Timing::Failed("no runs".into()),
+                Err(err) => Timing::Failed(err),
+            },
+        }));
+    }
+    let passed = print_table(&rows, &baseline, args.threshold);
+    if args.save && !passed && !args.force {
+        println!("baseline not written as parts failed or regressed, `--force` writes it anyway");
+    } else if args.save {
+        for row in &rows {
+            if let Timing::Measured(stats) = row.timing {
+                baseline.0.insert((row.day, row.part), stats);
+            }
+        }
+        baseline.save(&baseline_path)?;
+        println!("baseline written to {}", baseline_path.display());
+    }
+    Ok(passed)
+}
+
+/// Returns `false` if a part failed or regressed.
+fn print_table(rows: &[Row], baseline: &Baseline, threshold: f64) -> bool {
+    println!(
+        "{:>3} {:>4} {:>10} {:>10} {:>10} {:>10}  details",
+        "day", "part", "min", "median", "max", "change"
+    );
+    let mut passed = true;
+    for row in rows {
+        let line = match &row.timing {
+            Timing::Measured(stats) => {
+                let (change, details) = match baseline.0.get(&(row.day, row.part)) {
+                    Some(base) => {
+                        let change = change(stats, base);
+                        let regressed = change > threshold;
+                        passed &= !regressed;
+                        (
+                            format!("{change:+.1}%"),
+                            if regressed { "REGRESSION" } else { "" },
+                        )
+                    }
+                    None => ("-".into(), "no baseline"),
+                };
+                format!(
+                    "{:>3} {:>4} {:>10} {:>10} {:>10} {:>10}  {details}",
+                    row.day,
+                    row.part,
+                    format!("{:.1?}", stats.min),
+                    format!("{:.1?}", stats.median),
+                    format!("{:.1?}", stats.max),
+                    change
+                )
+            }
+            Timing::Failed(err) => {
+                passed = false;
+                format!(
+                    "{:>3} {:>4} {:>10} {:>10} {:>10} {:>10}  FAIL {err}",
+                    row.day, row.part, "-", "-", "-", "-"
+                )
+            }
+        };
+        println!("{}", line.trim_end());
+    }
+    passed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{change, Baseline, Stats};
+
+    fn ms(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(
+            Stats::new(ms(&[5, 1, 3])),
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5),
+            })
+        );
+        assert_eq!(
+            Stats::new(ms(&[4, 1, 2, 8])).map(|s| s.median),
+            Some(Duration::from_millis(3))
+        );
+        assert_eq!(Stats::new(vec![]), None);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let baseline: Baseline = "# day part min median max\n20 1 10 20 30\n7 2 1 2 3\n"
+            .parse()
+            .unwrap();
+        assert_eq!(baseline.0.len(), 2);
+        assert_eq!(baseline.to_string().parse(), Ok(baseline));
+        assert!("20 1 10 20".parse::<Baseline>().is_err());
+        assert!("300 1 10 20 30".parse::<Baseline>().is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = |median| Stats::new(ms(&[median])).unwrap();
+        assert!((change(&stats(110), &stats(100)) - 10.0).abs() < 1e-9);
+        assert!(change(&stats(50), &stats(100)) < 0.0);
+        assert_eq!(change(&stats(50), &stats(0)), 0.0);
+    }
+}
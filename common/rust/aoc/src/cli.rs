@@ -0,0 +1,504 @@
+//! Command line parsing for the `aoc` binary and the per day binaries.
+
+use std::path::PathBuf;
+
+use crate::options;
+
+pub const USAGE: &str = "\
+usage: aoc run --day <day> [--part <1|2>] [--input <path>|-] [--record <file>]
+               [--image <file>] [--dot <file>] [--set <name>=<value>]...
+       aoc run --all
+       aoc verify [--day <day>]
+       aoc bench [--day <day>] [--runs <n>] [--threshold <percent>]
+                 [--save [--force]]
+       aoc new-day --day <day>
+       aoc examples [--day <day>] [--save]
+       aoc stars
+       aoc fetch --day <day>
+       aoc submit --day <day> --part <1|2> [--answer <answer>]
+       aoc play <file> [--speed <factor>]
+
+The input defaults to $AOC_INPUT_DIR/<year>/day<day> if it exists, to the
+downloaded or puzzle_input file otherwise. `verify` checks the parts against
+the answers recorded in answers.txt of the workspace. `bench` times the
+parts with a recorded answer and compares them to bench_baseline.txt,
+`--save` updates the baseline unless a part failed or regressed, `--force`
+saves it anyway. `new-day` creates the crate of a day and
+registers it in the workspace. `examples` extracts the examples from the
+README of the days and reports test inputs that drifted from it, `--save`
+writes them as fixtures. `stars` shows the progress of all year workspaces.
+`fetch` downloads the input of a day with the session cookie in
+$AOC_SESSION into $AOC_INPUT_DIR or the inputs directory of the repository,
+a downloaded input is never requested again. `submit` sends the answer of
+a part, computed on its input unless `--answer` is given, and logs the
+verdict so the same wrong answer is never sent twice. `--record` writes the
+frames of a simulation as an asciicast file, which `play` shows. `--image`
+writes the final state of a grid as a .png or .ppm file, `--dot` the graph
+of a puzzle as a Graphviz file. `--set` passes an
+option to the day, such as the unknown monkey of 2022 day 21.";
+
+pub const DAY_USAGE: &str = "usage: <day> [--part <1|2>] [--input <path>|-] [--record <file>] \
+[--image <file>] [--dot <file>] [--set <name>=<value>]...";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify {
+        day: Option<u8>,
+    },
+    Bench(BenchArgs),
+    NewDay {
+        day: u8,
+    },
+    Examples {
+        day: Option<u8>,
+        save: bool,
+    },
+    Stars,
+    Fetch {
+        day: u8,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+        answer: Option<String>,
+    },
+    Play {
+        path: PathBuf,
+        speed: f64,
+    },
+    Help,
+}
+
+/// Arguments of the `run` command.
+#[derive(Debug, Default, PartialEq)]
+pub struct RunArgs {
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub all: bool,
+    /// File to write the recorded frames of the simulation to.
+    pub record: Option<PathBuf>,
+    /// File to write the image of the final state to.
+    pub image: Option<PathBuf>,
+    /// File to write the graph of the puzzle to.
+    pub dot: Option<PathBuf>,
+    /// Options passed to the day as `<name>=<value>`.
+    pub options: Vec<(String, String)>,
+}
+
+/// Arguments of the `bench` command.
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub day: Option<u8>,
+    /// Number of times every part is run.
+    pub runs: u32,
+    /// Allowed slowdown of the median compared to the baseline, in percent.
+    pub threshold: f64,
+    /// Whether to write the timings to the baseline.
+    pub save: bool,
+    /// Whether to save even if a part failed or regressed.
+    pub force: bool,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs {
+            day: None,
+            runs: 10,
+            threshold: 10.0,
+            save: false,
+            force: false,
+        }
+    }
+}
+
+/// Parses the arguments of the `aoc` binary (without the program name).
+pub fn parse_command(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => {
+            let run = parse_run_args(args)?;
+            match (run.day, run.all) {
+                (None, false) => Err("either `--day` or `--all` is required".into()),
+                (Some(_), true) => Err("`--day` and `--all` are mutually exclusive".into()),
+                (None, true)
+                    if run.part.is_some()
+                        || run.input.is_some()
+                        || run.record.is_some()
+                        || run.image.is_some()
+                        || run.dot.is_some()
+                        || !run.options.is_empty() =>
+                {
+                    Err("`--all` can't be combined with the options of a single day".into())
+                }
+                _ => Ok(Command::Run(run)),
+            }
+        }
+        Some("verify") => match parse_run_args(args)? {
+            RunArgs {
+                day,
+                part: None,
+                input: None,
+                all: false,
+                record: None,
+                image: None,
+                dot: None,
+                options,
+            } if options.is_empty() => Ok(Command::Verify { day }),
+            _ => Err("`verify` only supports `--day`".into()),
+        },
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some("new-day") => match parse_run_args(args)? {
+            RunArgs {
+                day: Some(day),
+                part: None,
+                input: None,
+                all: false,
+                record: None,
+                image: None,
+                dot: None,
+                options,
+            } if options.is_empty() => Ok(Command::NewDay { day }),
+            _ => Err("`new-day` requires `--day` and nothing else".into()),
+        },
+        Some("examples") => parse_examples_args(args),
+        Some("fetch") => match parse_run_args(args)? {
+            RunArgs {
+                day: Some(day),
+                part: None,
+                input: None,
+                all: false,
+                record: None,
+                image: None,
+                dot: None,
+                options,
+            } if options.is_empty() => Ok(Command::Fetch { day }),
+            _ => Err("`fetch` requires `--day` and nothing else".into()),
+        },
+        Some("submit") => parse_submit_args(args),
+        Some("play") => parse_play_args(args),
+        Some("stars") => match args.next() {
+            None => Ok(Command::Stars),
+            Some(arg) => Err(format!("unexpected argument `{arg}`")),
+        },
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(cmd) => Err(format!("unknown command `{cmd}`")),
+    }
+}
+
+/// Parses the options of the `run` command.
+pub fn parse_run_args(args: impl IntoIterator<Item = String>) -> Result<RunArgs, String> {
+    let mut run = RunArgs::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => run.day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--part" | "-p" => run.part = Some(parse_number(&arg, args.next(), 1..=2)?),
+            "--input" | "-i" => run.input = Some(expect_value(&arg, args.next())?.into()),
+            "--all" | "-a" => run.all = true,
+            "--record" | "-r" => run.record = Some(expect_value(&arg, args.next())?.into()),
+            "--image" => run.image = Some(expect_value(&arg, args.next())?.into()),
+            "--dot" => run.dot = Some(expect_value(&arg, args.next())?.into()),
+            "--set" => run
+                .options
+                .push(options::parse(&expect_value(&arg, args.next())?)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(run)
+}
+
+fn parse_bench_args(args: impl IntoIterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut bench = BenchArgs::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => bench.day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--runs" | "-n" => {
+                let value = expect_value(&arg, args.next())?;
+                bench.runs =
+                    value.parse().ok().filter(|&runs| runs > 0).ok_or_else(|| {
+                        format!("`{arg}` expects a positive number, got `{value}`")
+                    })?;
+            }
+            "--threshold" | "-t" => {
+                let value = expect_value(&arg, args.next())?;
+                bench.threshold = value
+                    .parse()
+                    .ok()
+                    .filter(|&t: &f64| t >= 0.0)
+                    .ok_or_else(|| format!("`{arg}` expects a percentage, got `{value}`"))?;
+            }
+            "--save" => bench.save = true,
+            "--force" => bench.force = true,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    if bench.force && !bench.save {
+        return Err("`--force` only applies to `--save`".into());
+    }
+    Ok(bench)
+}
+
+fn parse_examples_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let (mut day, mut save) = (None, false);
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--save" => save = true,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(Command::Examples { day, save })
+}
+
+fn parse_submit_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let (mut day, mut part, mut answer) = (None, None, None);
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--part" | "-p" => part = Some(parse_number(&arg, args.next(), 1..=2)?),
+            "--answer" | "-a" => answer = Some(expect_value(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    match (day, part) {
+        (Some(day), Some(part)) => Ok(Command::Submit { day, part, answer }),
+        _ => Err("`submit` requires `--day` and `--part`".into()),
+    }
+}
+
+fn parse_play_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let (mut path, mut speed) = (None, 1.0);
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--speed" | "-s" => {
+                let value = expect_value(&arg, args.next())?;
+                speed = value
+                    .parse()
+                    .ok()
+                    .filter(|&s: &f64| s > 0.0 && s.is_finite())
+                    .ok_or_else(|| format!("`{arg}` expects a positive factor, got `{value}`"))?;
+            }
+            _ if path.is_none() && !arg.starts_with('-') => path = Some(arg.into()),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    let path = path.ok_or("`play` requires the file of a recording")?;
+    Ok(Command::Play { path, speed })
+}
+
+fn expect_value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("`{flag}` expects a value"))
+}
+
+fn parse_number(
+    flag: &str,
+    value: Option<String>,
+    range: std::ops::RangeInclusive<u8>,
+) -> Result<u8, String> {
+    let value = expect_value(flag, value)?;
+    value
+        .parse()
+        .ok()
+        .filter(|v| range.contains(v))
+        .ok_or_else(|| {
+            format!(
+                "`{flag}` expects a number between {} and {}, got `{value}`",
+                range.start(),
+                range.end()
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_command, BenchArgs, Command, RunArgs};
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_command(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run_day() {
+        assert_eq!(
+            parse("run --day 15 --part 2 --input some/file"),
+            Ok(Command::Run(RunArgs {
+                day: Some(15),
+                part: Some(2),
+                input: Some("some/file".into()),
+                all: false,
+                record: None,
+                image: None,
+                dot: None,
+                options: vec![],
+            }))
+        );
+        assert_eq!(
+            parse("run --day 14 --record cave.cast --image cave.png --dot cave.dot"),
+            Ok(Command::Run(RunArgs {
+                day: Some(14),
+                record: Some("cave.cast".into()),
+                image: Some("cave.png".into()),
+                dot: Some("cave.dot".into()),
+                ..Default::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_options() {
+        assert_eq!(
+            parse("run --day 21 --set variable=pppw --set empty="),
+            Ok(Command::Run(RunArgs {
+                day: Some(21),
+                options: vec![
+                    ("variable".into(), "pppw".into()),
+                    ("empty".into(), "".into())
+                ],
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            parse("run --day 21 --set =pppw"),
+            Err("`--set` expects `<name>=<value>`, got `=pppw`".into())
+        );
+        assert!(parse("run --all --set variable=pppw").is_err());
+        assert!(parse("verify --set variable=pppw").is_err());
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        assert_eq!(
+            parse("run --all"),
+            Ok(Command::Run(RunArgs {
+                all: true,
+                ..Default::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(parse("verify"), Ok(Command::Verify { day: None }));
+        assert_eq!(parse("verify -d 3"), Ok(Command::Verify { day: Some(3) }));
+        assert!(parse("verify --all").is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day() {
+        assert_eq!(parse("new-day -d 22"), Ok(Command::NewDay { day: 22 }));
+        assert!(parse("new-day").is_err());
+        assert!(parse("new-day --day 26").is_err());
+        assert!(parse("new-day --day 3 --part 1").is_err());
+        assert_eq!(parse("fetch --day 3"), Ok(Command::Fetch { day: 3 }));
+        assert!(parse("fetch --all").is_err());
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        assert_eq!(
+            parse("examples"),
+            Ok(Command::Examples {
+                day: None,
+                save: false
+            })
+        );
+        assert_eq!(
+            parse("examples --save -d 4"),
+            Ok(Command::Examples {
+                day: Some(4),
+                save: true
+            })
+        );
+        assert!(parse("examples --part 1").is_err());
+        assert_eq!(parse("stars"), Ok(Command::Stars));
+        assert!(parse("stars --day 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(
+            parse("submit --day 5 --part 2 --answer CMZ"),
+            Ok(Command::Submit {
+                day: 5,
+                part: 2,
+                answer: Some("CMZ".into())
+            })
+        );
+        assert_eq!(
+            parse("submit -d 5 -p 1"),
+            Ok(Command::Submit {
+                day: 5,
+                part: 1,
+                answer: None
+            })
+        );
+        assert!(parse("submit --day 5").is_err());
+        assert!(parse("submit --day 5 --part 1 --answer").is_err());
+    }
+
+    #[test]
+    fn test_parse_play() {
+        assert_eq!(
+            parse("play cave.cast --speed 2.5"),
+            Ok(Command::Play {
+                path: "cave.cast".into(),
+                speed: 2.5
+            })
+        );
+        assert!(parse("play").is_err());
+        assert!(parse("play cave.cast --speed 0").is_err());
+        assert!(parse("play a.cast b.cast").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(parse("bench"), Ok(Command::Bench(BenchArgs::default())));
+        assert_eq!(
+            parse("bench --day 20 --runs 5 --threshold 2.5 --save"),
+            Ok(Command::Bench(BenchArgs {
+                day: Some(20),
+                runs: 5,
+                threshold: 2.5,
+                save: true,
+                force: false,
+            }))
+        );
+        assert_eq!(
+            parse("bench --save --force"),
+            Ok(Command::Bench(BenchArgs {
+                save: true,
+                force: true,
+                ..BenchArgs::default()
+            }))
+        );
+        assert!(parse("bench --force").is_err());
+        assert!(parse("bench --runs 0").is_err());
+        assert!(parse("bench --threshold -1").is_err());
+        assert!(parse("bench --part 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("run").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day 26").is_err());
+        assert!(parse("run --day 1 --part 3").is_err());
+        assert!(parse("run --day 1 --all").is_err());
+        assert!(parse("run --all --part 1").is_err());
+        assert!(parse("run --all --record all.cast").is_err());
+        assert!(parse("run --day 1 --verbose").is_err());
+        assert!(parse("walk").is_err());
+    }
+
+    #[test]
+    fn test_parse_help() {
+        assert_eq!(parse(""), Ok(Command::Help));
+        assert_eq!(parse("--help"), Ok(Command::Help));
+    }
+}
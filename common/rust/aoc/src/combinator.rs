@@ -0,0 +1,407 @@
+//! Small parser combinators for puzzle inputs.
+//!
+//! A parser takes the remaining input and returns the parsed value with the
+//! input following it. The remaining input is always a slice of the whole
+//! input, so [`parse_all`] can turn a [`Failure`] into a [`ParseError`] with
+//! line and column:
+//!
+//! ```
+//! use aoc::combinator::{field, integer, lines, pair, parse_all, preceded, Parser};
+//!
+//! let robot = pair(
+//!     field("p", "=", pair(integer::<u32>(), preceded(",", integer()))),
+//!     preceded(" ", field("v", "=", pair(integer::<i32>(), preceded(",", integer())))),
+//! );
+//! let robots = parse_all(lines(robot), "p=0,4 v=3,-3\np=6,3 v=-1,-3\n").unwrap();
+//! assert_eq!(robots[1], ((6, 3), (-1, -3)));
+//!
+//! let err = parse_all(lines(integer::<u8>().map(|n| n * 2)), "1\n2\nx\n").unwrap_err();
+//! assert_eq!(err.to_string(), "line 3, column 1: expected a number, found `x`");
+//! ```
+//!
+//! Plain functions `fn(&str) -> PResult<'_, T>` are parsers as well, which
+//! is handy for records of many fields.
+
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+/// The parsed value and the remaining input, or where and why parsing failed.
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// A failed parser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The offending text, empty at the end of the input.
+    pub found: &'a str,
+    pub expected: String,
+}
+
+impl<'a> Failure<'a> {
+    /// A failure at the first character of `input`.
+    pub fn new(input: &'a str, expected: impl Into<String>) -> Self {
+        let len = input.chars().next().map_or(0, char::len_utf8);
+        Failure {
+            found: &input[..len],
+            expected: expected.into(),
+        }
+    }
+
+    /// The remaining input at the failure, used to pick the furthest one.
+    fn rest(&self) -> usize {
+        self.found.as_ptr() as usize
+    }
+}
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| self.parse(input).map(|(value, rest)| (f(value), rest))
+    }
+
+    /// Converts the value, an error fails at the start of the parsed text.
+    fn try_map<U, E: Into<String>>(self, f: impl Fn(T) -> Result<U, E>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (value, rest) = self.parse(input)?;
+            match f(value) {
+                Ok(value) => Ok((value, rest)),
+                Err(expected) => Err(Failure {
+                    found: &input[..input.len() - rest.len()],
+                    expected: expected.into(),
+                }),
+            }
+        }
+    }
+
+    /// Borrows the parser to reuse it in another one.
+    fn by_ref(&self) -> impl Parser<'a, T> + '_ {
+        move |input: &'a str| self.parse(input)
+    }
+
+    /// Tries `other` if this parser fails, the failure which got further is
+    /// reported if both do.
+    fn or(self, other: impl Parser<'a, T>) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let first = match self.parse(input) {
+                Ok(parsed) => return Ok(parsed),
+                Err(failure) => failure,
+            };
+            other
+                .parse(input)
+                .map_err(|second| match first.rest().cmp(&second.rest()) {
+                    std::cmp::Ordering::Greater => first,
+                    std::cmp::Ordering::Less => second,
+                    std::cmp::Ordering::Equal => Failure {
+                        found: first.found,
+                        expected: format!("{} or {}", first.expected, second.expected),
+                    },
+                })
+        }
+    }
+}
+
+impl<'a, T, F: Fn(&'a str) -> PResult<'a, T>> Parser<'a, T> for F {
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// String literals match themselves.
+impl<'a> Parser<'a, &'a str> for &str {
+    fn parse(&self, input: &'a str) -> PResult<'a, &'a str> {
+        literal(self).parse(input)
+    }
+}
+
+/// Matches `text` exactly.
+pub fn literal<'a>(text: &str) -> impl Parser<'a, &'a str> + '_ {
+    move |input: &'a str| match input.strip_prefix(text) {
+        Some(rest) => Ok((&input[..text.len()], rest)),
+        None => {
+            let len = input
+                .char_indices()
+                .nth(text.chars().count())
+                .map_or(input.len(), |(i, _)| i);
+            let found = &input[..len];
+            Err(Failure {
+                found: &found[..found.find('\n').unwrap_or(found.len())],
+                expected: format!("`{}`", text.escape_debug()),
+            })
+        }
+    }
+}
+
+/// A decimal integer with an optional sign.
+pub fn integer<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with(['-', '+']));
+        let digits = input[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Err(Failure::new(input, "a number"));
+        }
+        let (number, rest) = input.split_at(sign + digits);
+        match number.parse() {
+            Ok(value) => Ok((value, rest)),
+            Err(_) => Err(Failure {
+                found: number,
+                expected: format!("a number of type {}", std::any::type_name::<T>()),
+            }),
+        }
+    }
+}
+
+/// One or more characters matching `pred`.
+pub fn take_while1<'a>(
+    pred: impl Fn(char) -> bool,
+    expected: &'static str,
+) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let len = input.find(|c| !pred(c)).unwrap_or(input.len());
+        match len {
+            0 => Err(Failure::new(input, expected)),
+            len => Ok(input.split_at(len)),
+        }
+    }
+}
+
+/// A name of letters, digits and underscores.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    take_while1(|c| c.is_alphanumeric() || c == '_', "a word")
+}
+
+/// Zero or more spaces.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let len = input.len() - input.trim_start_matches(' ').len();
+        Ok(input.split_at(len))
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// The value of `parser` following `prefix`.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    pair(prefix, parser).map(|(_, value)| value)
+}
+
+/// The value of `parser` followed by `suffix`.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    pair(parser, suffix).map(|(value, _)| value)
+}
+
+pub fn delimited<'a, P, T, S>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    preceded(prefix, terminated(parser, suffix))
+}
+
+/// `None` instead of a failure.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: &'a str| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// A keyed record `<key><separator><value>`, e.g. `x=3` or `Test: 17`.
+pub fn field<'a, T>(
+    key: &'a str,
+    separator: &'a str,
+    value: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    preceded(pair(literal(key), literal(separator)), value)
+}
+
+/// One or more items separated by `separator`.
+pub fn separated<'a, T>(item: impl Parser<'a, T>, separator: &'a str) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Some(next) = rest.strip_prefix(separator) {
+            let (value, after) = item.parse(next)?;
+            items.push(value);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Ends at a line break, which is consumed, or at the end of the input.
+fn end_of_line(input: &str) -> PResult<'_, ()> {
+    match input.strip_prefix('\n') {
+        Some(rest) => Ok(((), rest)),
+        None if input.is_empty() => Ok(((), input)),
+        None => Err(Failure::new(input, "the end of the line")),
+    }
+}
+
+/// One item per line, until the end of the input or an empty line.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut items = vec![];
+        while !input.is_empty() && !input.starts_with('\n') {
+            let (value, rest) = item.parse(input)?;
+            let (_, rest) = end_of_line(rest)?;
+            items.push(value);
+            input = rest;
+        }
+        Ok((items, input))
+    }
+}
+
+/// Blocks separated by blank lines, each block has to be consumed by `item`
+/// except for its final line break.
+pub fn blocks<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut items = vec![];
+        loop {
+            input = input.trim_start_matches('\n');
+            if input.is_empty() {
+                return Ok((items, input));
+            }
+            let (value, rest) = item.parse(input)?;
+            let (_, rest) = end_of_line(rest)?;
+            if !rest.is_empty() && !rest.starts_with('\n') {
+                return Err(Failure::new(rest, "a blank line"));
+            }
+            items.push(value);
+            input = rest;
+        }
+    }
+}
+
+/// Runs `parser` on the whole `input`, only trailing whitespace may be left.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, input: &'a str) -> Result<T, ParseError> {
+    let failure = match parser.parse(input) {
+        Ok((value, rest)) if rest.trim().is_empty() => return Ok(value),
+        Ok((_, rest)) => Failure::new(rest.trim_start(), "the end of the input"),
+        Err(failure) => failure,
+    };
+    Err(ParseError::at(input, failure.found, failure.expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        blocks, delimited, field, integer, lines, opt, pair, parse_all, preceded, separated,
+        spaces, word, Failure, Parser,
+    };
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer::<i32>().parse("-12 x"), Ok((-12, " x")));
+        assert_eq!(integer::<u8>().parse("+7"), Ok((7, "")));
+        assert_eq!(
+            integer::<u8>().parse("300,"),
+            Err(Failure {
+                found: "300",
+                expected: "a number of type u8".into()
+            })
+        );
+        assert_eq!(
+            integer::<u8>().parse("-"),
+            Err(Failure::new("-", "a number"))
+        );
+    }
+
+    #[test]
+    fn test_sequences() {
+        let list = delimited("[", separated(integer::<u32>(), ", "), "]");
+        assert_eq!(list.parse("[1, 2, 3]!"), Ok((vec![1, 2, 3], "!")));
+        assert_eq!(
+            list.parse("[1, 2; 3]"),
+            Err(Failure {
+                found: ";",
+                expected: "`]`".into()
+            })
+        );
+        let assignment = pair(
+            word(),
+            preceded(pair(spaces(), "="), preceded(spaces(), integer::<u8>())),
+        );
+        assert_eq!(assignment.parse("a_1  = 4"), Ok((("a_1", 4), "")));
+        assert_eq!(opt(integer::<u8>()).parse("x"), Ok((None, "x")));
+    }
+
+    #[test]
+    fn test_or_and_try_map() {
+        let sign = "+".map(|_| 1).or("-".map(|_| -1));
+        assert_eq!(sign.parse("-3"), Ok((-1, "3")));
+        assert_eq!(
+            sign.parse("*3"),
+            Err(Failure {
+                found: "*",
+                expected: "`+` or `-`".into()
+            })
+        );
+        let even = integer::<u32>().try_map(|n| {
+            if n % 2 == 0 {
+                Ok(n)
+            } else {
+                Err("an even number")
+            }
+        });
+        assert_eq!(
+            even.parse("17 "),
+            Err(Failure {
+                found: "17",
+                expected: "an even number".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_lines_and_blocks() {
+        let input = "\nname: a\nsize: 1\n\nname: b\nsize: 22\n";
+        let record = pair(
+            field("name", ": ", word()),
+            preceded("\n", field("size", ": ", integer::<u32>())),
+        );
+        assert_eq!(
+            parse_all(blocks(record.by_ref()), input),
+            Ok(vec![("a", 1), ("b", 22)])
+        );
+        let err = parse_all(blocks(record.by_ref()), "name: a\nsize: 1\nname: b\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a blank line, found `n`"
+        );
+        let err = parse_all(lines(integer::<u8>()), "1\n2 3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected the end of the line, found ` `"
+        );
+        let err = parse_all(lines(integer::<u8>()), "1\n\n2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected the end of the input, found `2`"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Cycle detection for simulations run far beyond what can be stepped.
+//!
+//! A simulation whose state repeats is periodic from the first repeated
+//! state on. [`brent`] and [`floyd`] find the [`Cycle`] of a state advanced
+//! by a function in constant memory. A [`Detector`] is fed the snapshots of a
+//! running simulation instead and remembers them in a hash map, so the values
+//! computed along the way are kept. [`Cycle::extrapolate`] then tells the
+//! value at any step:
+//!
+//! ```
+//! use aoc::cycle::Detector;
+//!
+//! // the state repeats every 3 steps from step 2 on, the value grows by 5
+//! let states = [9, 8, 0, 1, 2, 0];
+//! let values = [0, 1, 4, 6, 7, 9];
+//! let mut detector = Detector::new();
+//! let cycle = states.into_iter().find_map(|s| detector.push(s)).unwrap();
+//! assert_eq!((cycle.start, cycle.length), (2, 3));
+//! assert_eq!(cycle.extrapolate(&values, 8), 14);
+//! ```
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+/// The states from step `start` on repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start + self.length {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The value at step `n` of a value that changes by the same amount in
+    /// every cycle, `values` are the values of the steps up to the end of
+    /// the first cycle (`start + length`) or further.
+    pub fn extrapolate<T>(&self, values: &[T], n: usize) -> T
+    where
+        T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + TryFrom<usize>,
+    {
+        let end = self.start + self.length;
+        assert!(
+            values.len() > end,
+            "the values have to include step {end}, the end of the first cycle"
+        );
+        if n < values.len() {
+            return values[n];
+        }
+        let Ok(cycles) = T::try_from((n - self.start) / self.length) else {
+            panic!("the number of cycles up to step {n} doesn't fit the values");
+        };
+        values[self.equivalent(n)] + cycles * (values[end] - values[self.start])
+    }
+}
+
+/// Finds the cycle of the states reached from `start` by `step` with Brent's
+/// algorithm, which calls `step` fewer times than [`floyd`].
+///
+/// Doesn't terminate if the states never repeat.
+pub fn brent<T: PartialEq + Clone>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // the hare searches the length, the tortoise waits at powers of two
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+    // the hare runs `length` ahead and meets the tortoise at the start
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+    Cycle {
+        start: cycle_start,
+        length,
+    }
+}
+
+/// Finds the cycle of the states reached from `start` by `step` with Floyd's
+/// tortoise and hare.
+///
+/// Doesn't terminate if the states never repeat.
+pub fn floyd<T: PartialEq + Clone>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+    let mut cycle_start = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle {
+        start: cycle_start,
+        length,
+    }
+}
+
+/// Finds the cycle of the snapshots of a running simulation.
+///
+/// A snapshot has to determine all later ones, the step it was taken at
+/// is its position in the order of [`push`](Detector::push).
+#[derive(Debug, Clone)]
+pub struct Detector<K> {
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq> Default for Detector<K> {
+    fn default() -> Self {
+        Detector {
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> Detector<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the snapshot of the next step, the cycle is returned once a
+    /// snapshot repeats.
+    pub fn push(&mut self, snapshot: K) -> Option<Cycle> {
+        let step = self.seen.len();
+        match self.seen.get(&snapshot) {
+            Some(&start) => Some(Cycle {
+                start,
+                length: step - start,
+            }),
+            None => {
+                self.seen.insert(snapshot, step);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, floyd, Cycle, Detector};
+
+    /// `x² + 1 mod 255` from 3 runs 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_brent_and_floyd() {
+        let expected = Cycle {
+            start: 2,
+            length: 6,
+        };
+        assert_eq!(brent(3, step), expected);
+        assert_eq!(floyd(3, step), expected);
+        // a fixed point
+        let fixed = Cycle {
+            start: 0,
+            length: 1,
+        };
+        assert_eq!(brent(7, |&x| x), fixed);
+        assert_eq!(floyd(7, |&x| x), fixed);
+    }
+
+    #[test]
+    fn test_detector() {
+        let mut detector = Detector::new();
+        let mut states = vec![3];
+        let cycle = loop {
+            let state = *states.last().unwrap();
+            if let Some(cycle) = detector.push(state) {
+                break cycle;
+            }
+            states.push(step(&state));
+        };
+        assert_eq!(cycle, brent(3, step));
+        assert_eq!(states.len(), cycle.start + cycle.length + 1);
+        assert_eq!(cycle.equivalent(1_000), 2 + 998 % 6);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let cycle = Cycle {
+            start: 1,
+            length: 2,
+        };
+        // +3 per cycle: 0, [1, 2], [4, 5], [7, 8], ...
+        let values: [u64; 4] = [0, 1, 2, 4];
+        assert_eq!(cycle.extrapolate(&values, 2), 2);
+        assert_eq!(cycle.extrapolate(&values, 5), 7);
+        assert_eq!(cycle.extrapolate(&values, 6), 8);
+        assert_eq!(
+            cycle.extrapolate(&values, 1_000_000_000_000),
+            1_499_999_999_999
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Export of graphs in the DOT language of [Graphviz](https://graphviz.org/).
+//!
+//! A [`Graph`] is built node by node and edge by edge, every element takes
+//! DOT attributes such as `label`, `color` or `shape`. Like
+//! [`image`](crate::image), a day hands its graph to [`snapshot`], which only
+//! builds it if the runner was started with `--dot <file>`:
+//!
+//! ```
+//! use aoc::dot::Graph;
+//!
+//! let mut graph = Graph::new("monkeys");
+//! graph.node("root").attr("label", "root: a + b");
+//! graph.edge("root", "a").attr("color", "red");
+//! assert_eq!(
+//!     graph.to_string(),
+//!     "digraph \"monkeys\" {\n  \"root\" [label=\"root: a + b\"];\n  \"root\" -> \"a\" [color=\"red\"];\n}\n"
+//! );
+//! ```
+//!
+//! `dot -Tsvg <file> -o graph.svg` renders the file.
+
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    sync::Mutex,
+};
+
+/// The attributes of a node, an edge or the whole graph.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Attributes(Vec<(String, String)>);
+
+impl Attributes {
+    /// Sets the attribute `name`, replacing an earlier value.
+    pub fn attr(&mut self, name: &str, value: impl Display) -> &mut Self {
+        let value = value.to_string();
+        match self.0.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((name.into(), value)),
+        }
+        self
+    }
+}
+
+impl Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        let attrs: Vec<_> = self
+            .0
+            .iter()
+            .map(|(name, value)| format!("{name}={}", quote(value)))
+            .collect();
+        write!(f, " [{}]", attrs.join(", "))
+    }
+}
+
+/// A directed graph, nodes and edges are written in the order they were
+/// added.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph {
+    name: String,
+    attrs: Attributes,
+    nodes: Vec<(String, Attributes)>,
+    edges: Vec<(String, String, Attributes)>,
+}
+
+impl Graph {
+    pub fn new(name: &str) -> Self {
+        Graph {
+            name: name.into(),
+            attrs: Attributes::default(),
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+
+    /// The attributes of the graph itself, such as `rankdir`.
+    pub fn attrs(&mut self) -> &mut Attributes {
+        &mut self.attrs
+    }
+
+    /// Adds the node `id`, whose label is the id unless set otherwise.
+    pub fn node(&mut self, id: &str) -> &mut Attributes {
+        self.nodes.push((id.into(), Attributes::default()));
+        &mut self.nodes.last_mut().expect("a node was just added").1
+    }
+
+    /// Adds an edge from the node `from` to the node `to`, nodes that
+    /// weren't added are created by Graphviz.
+    pub fn edge(&mut self, from: &str, to: &str) -> &mut Attributes {
+        self.edges
+            .push((from.into(), to.into(), Attributes::default()));
+        &mut self.edges.last_mut().expect("an edge was just added").2
+    }
+
+    /// Writes the graph to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }
+}
+
+impl Display for Graph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {} {{", quote(&self.name))?;
+        for (name, value) in &self.attrs.0 {
+            writeln!(f, "  {name}={};", quote(value))?;
+        }
+        for (id, attrs) in &self.nodes {
+            writeln!(f, "  {}{attrs};", quote(id))?;
+        }
+        for (from, to, attrs) in &self.edges {
+            writeln!(f, "  {} -> {}{attrs};", quote(from), quote(to))?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// `s` as a quoted DOT id, a newline is a centered line break.
+fn quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+/// The graph of the running day, `None` inside unless exporting.
+static SNAPSHOT: Mutex<Option<Option<Graph>>> = Mutex::new(None);
+
+/// Starts keeping the graphs handed to [`snapshot`].
+pub fn start() {
+    *SNAPSHOT.lock().unwrap_or_else(|err| err.into_inner()) = Some(None);
+}
+
+/// Stops exporting and returns the last graph.
+pub fn finish() -> Option<Graph> {
+    SNAPSHOT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .take()
+        .flatten()
+}
+
+/// Builds and keeps the graph if exporting was [`start`]ed, a later graph
+/// replaces an earlier one.
+pub fn snapshot(graph: impl FnOnce() -> Graph) {
+    if let Some(snapshot) = SNAPSHOT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .as_mut()
+    {
+        *snapshot = Some(graph());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graph;
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::new("g");
+        graph.attrs().attr("rankdir", "LR");
+        graph
+            .node("a")
+            .attr("label", "say \"hi\"\nC:\\")
+            .attr("shape", "box")
+            .attr("label", "a\n1");
+        graph.node("b");
+        graph.edge("a", "b");
+        assert_eq!(
+            graph.to_string(),
+            r#"digraph "g" {
+  rankdir="LR";
+  "a" [label="a\n1", shape="box"];
+  "b";
+  "a" -> "b";
+}
+"#
+        );
+        let mut quoted = Graph::new("q");
+        quoted.node("x").attr("label", "say \"hi\" C:\\");
+        assert!(quoted
+            .to_string()
+            .contains(r#""x" [label="say \"hi\" C:\\"];"#));
+    }
+}
@@ -0,0 +1,380 @@
+//! The `examples` command and the example tests generated from the READMEs.
+//!
+//! `aoc examples` extracts the examples of every day from its README (see
+//! [`readme`](crate::readme)) and reports the test constants of the day that
+//! no longer match any example of the README. With `--save` the examples are
+//! written as fixtures next to the crate of the day:
+//!
+//! ```text
+//! day1/fixtures/part1.txt
+//! day1/fixtures/part2.txt
+//! day1/fixtures/answers.txt
+//! ```
+//!
+//! The answers use the format of the workspace [`answers`](crate::answers).
+//! A day opts into testing its parts against the fixtures with
+//! [`example_tests!`](crate::example_tests). Examples solved with other
+//! parameters than the real puzzle set these as [`options`](crate::options)
+//! in a hand written `fixtures/options.txt`, one `<name>=<value>` per line.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{self, Answers},
+    options, puzzle,
+    readme::{Example, Readme},
+    verify::describe,
+    Outcome, Puzzle, Workspace,
+};
+
+/// Directory of the fixtures, relative to the crate of a day.
+pub const DIR: &str = "fixtures";
+
+/// The options of the examples in the fixtures, optional.
+pub const OPTIONS_FILE: &str = "options.txt";
+
+/// Generates a test checking the given parts of a puzzle against the
+/// example fixtures of the calling crate.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::Day1;
+///
+///     aoc::example_tests!(Day1, 1, 2);
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($puzzle:ty, $($part:literal),+) => {
+        #[test]
+        fn test_examples() {
+            for part in [$($part),+] {
+                if let Err(err) =
+                    $crate::examples::check::<$puzzle>(env!("CARGO_MANIFEST_DIR"), part)
+                {
+                    panic!("{err}");
+                }
+            }
+        }
+    };
+}
+
+/// Runs `part` of `P` on its example fixture in the crate directory `dir`.
+pub fn check<P: Puzzle>(dir: &str, part: u8) -> Result<(), String> {
+    let dir = Path::new(dir).join(DIR);
+    let input_path = dir.join(format!("part{part}.txt"));
+    let input = fs::read_to_string(&input_path)
+        .map_err(|err| format!("failed to read {}: {err}", input_path.display()))?;
+    let answers = Answers::load(&dir.join(answers::FILE_NAME))?;
+    let (_, expected) = answers
+        .iter()
+        .find(|&(&(_, p), _)| p == part)
+        .ok_or_else(|| format!("no example answer of part {part}"))?;
+    let options = match fs::read_to_string(dir.join(OPTIONS_FILE)) {
+        Ok(text) => parse_options(&text)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+        Err(err) => return Err(format!("failed to read the example options: {err}")),
+    };
+    options::start(&options);
+    let parts = puzzle::solve::<P>(&input, &[part]).parts;
+    options::finish();
+    let outcome = match parts {
+        Ok(mut parts) => parts.remove(0).outcome,
+        Err(outcome) => return Err(format!("example input {}", describe(&outcome))),
+    };
+    match outcome {
+        Outcome::Solved(answer) if answer == *expected => Ok(()),
+        Outcome::Solved(answer) => Err(format!(
+            "part {part}: got {answer}, expected {expected} for the example"
+        )),
+        outcome => Err(format!("part {part}: {}", describe(&outcome))),
+    }
+}
+
+/// The `<name>=<value>` lines of an options file, `#` starts a comment.
+fn parse_options(text: &str) -> Result<Vec<(String, String)>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(options::parse)
+        .collect()
+}
+
+/// Writes the examples of `day` as fixtures into the crate directory `dir`.
+fn save_fixtures(dir: &Path, day: u8, examples: &[Example]) -> Result<PathBuf, String> {
+    let dir = dir.join(DIR);
+    let write = |path: PathBuf, contents: String| {
+        fs::write(&path, contents)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    };
+    fs::create_dir_all(&dir).map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+    let mut answers = String::from("# day part answer\n");
+    for example in examples {
+        write(
+            dir.join(format!("part{}.txt", example.part)),
+            format!("{}\n", example.input),
+        )?;
+        if let Some(answer) = &example.answer {
+            answers.push_str(&format!("{day} {} {answer}\n", example.part));
+        }
+    }
+    write(dir.join(answers::FILE_NAME), answers)?;
+    Ok(dir)
+}
+
+/// A string constant of the sources of a day.
+#[derive(Debug, PartialEq)]
+struct Constant {
+    name: String,
+    value: String,
+}
+
+/// The test inputs pasted into the sources, constants named like `TEST` or
+/// `EXAMPLE` spanning several lines.
+fn pasted_examples(dir: &Path) -> Result<Vec<Constant>, String> {
+    let src = dir.join("src");
+    let mut constants = vec![];
+    let Ok(entries) = fs::read_dir(&src) else {
+        return Ok(constants);
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    paths.sort();
+    for path in paths {
+        let source = fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        constants.extend(string_constants(&source).into_iter().filter(|c| {
+            (c.name.contains("TEST") || c.name.contains("EXAMPLE")) && c.value.trim().contains('\n')
+        }));
+    }
+    Ok(constants)
+}
+
+/// Finds the `const` and `static` items of type `&str` in `source`.
+fn string_constants(source: &str) -> Vec<Constant> {
+    let mut constants = vec![];
+    for (start, keyword) in source
+        .match_indices("const ")
+        .chain(source.match_indices("static "))
+    {
+        if source[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_')
+        {
+            continue;
+        }
+        let rest = &source[start + keyword.len()..];
+        let Some((name, rest)) = rest.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let Some(rest) = rest
+            .trim_start()
+            .strip_prefix('&')
+            .map(|r| r.trim_start().trim_start_matches("'static").trim_start())
+            .and_then(|r| r.strip_prefix("str"))
+            .and_then(|r| r.trim_start().strip_prefix('='))
+        else {
+            continue;
+        };
+        if let Some(value) = string_literal(rest.trim_start()) {
+            constants.push(Constant {
+                name: name.to_owned(),
+                value,
+            });
+        }
+    }
+    constants
+}
+
+/// The value of the string literal `source` starts with.
+fn string_literal(source: &str) -> Option<String> {
+    if let Some(raw) = source.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw[hashes..].strip_prefix('"')?;
+        let end = body.find(&format!("\"{}", "#".repeat(hashes)))?;
+        return Some(body[..end].to_owned());
+    }
+    let mut chars = source.strip_prefix('"')?.chars().peekable();
+    let mut value = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                'r' => value.push('\r'),
+                '0' => value.push('\0'),
+                '\n' => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+}
+
+/// What was found for a day.
+enum Status {
+    NoReadme,
+    NoExamples,
+    /// The names of the pasted examples missing in the README.
+    Checked(Vec<String>),
+}
+
+struct Row {
+    day: u8,
+    examples: Vec<Example>,
+    status: Status,
+}
+
+/// Extracts the examples of every day from its README, reports test
+/// constants that drifted from it and writes the fixtures if `save` is set.
+///
+/// Returns `false` if a pasted example is missing in the README.
+pub fn examples(workspace: &Workspace, day: Option<u8>, save: bool) -> Result<bool, String> {
+    let root = Path::new(workspace.root);
+    let mut rows = vec![];
+    for solution in workspace.solutions {
+        if day.is_some_and(|d| d != solution.day) {
+            continue;
+        }
+        let dir = root.join(format!("day{}", solution.day));
+        let Some(readme) = Readme::load(&dir)? else {
+            rows.push(Row {
+                day: solution.day,
+                examples: vec![],
+                status: Status::NoReadme,
+            });
+            continue;
+        };
+        if readme.examples.is_empty() {
+            rows.push(Row {
+                day: solution.day,
+                examples: vec![],
+                status: Status::NoExamples,
+            });
+            continue;
+        }
+        let drifted = pasted_examples(&dir)?
+            .into_iter()
+            .filter(|constant| !readme.contains(&constant.value))
+            .map(|constant| constant.name)
+            .collect();
+        if save {
+            let fixtures = save_fixtures(&dir, solution.day, &readme.examples)?;
+            println!(
+                "examples of day {} written to {}",
+                solution.day,
+                fixtures.display()
+            );
+        }
+        rows.push(Row {
+            day: solution.day,
+            examples: readme.examples,
+            status: Status::Checked(drifted),
+        });
+    }
+    rows.sort_by_key(|row| row.day);
+    Ok(print_table(&rows))
+}
+
+/// Returns `false` if an example drifted.
+fn print_table(rows: &[Row]) -> bool {
+    println!(
+        "{:>3} {:>5}  {:<16} {:<16} details",
+        "day", "lines", "part 1", "part 2"
+    );
+    let mut passed = true;
+    for row in rows {
+        let answer = |part: u8| {
+            row.examples
+                .iter()
+                .find(|e| e.part == part)
+                .map_or("-".into(), |e| {
+                    e.answer.as_ref().map_or("?".into(), |a| a.to_string())
+                })
+        };
+        let details = match &row.status {
+            Status::NoReadme => "no README".into(),
+            Status::NoExamples => "no example found in the README".into(),
+            Status::Checked(drifted) if drifted.is_empty() => String::new(),
+            Status::Checked(drifted) => {
+                passed = false;
+                format!("DRIFTED: `{}` not in the README", drifted.join("`, `"))
+            }
+        };
+        let lines = row
+            .examples
+            .first()
+            .map_or("-".into(), |e| e.input.lines().count().to_string());
+        let line = format!(
+            "{:>3} {:>5}  {:<16} {:<16} {details}",
+            row.day,
+            lines,
+            answer(1),
+            answer(2)
+        );
+        println!("{}", line.trim_end());
+    }
+    passed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_options, string_constants, string_literal, Constant};
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!(
+            parse_options("# the example\nrow=10\n\nsize=20\n"),
+            Ok(vec![
+                ("row".into(), "10".into()),
+                ("size".into(), "20".into())
+            ])
+        );
+        assert_eq!(
+            parse_options("row 10\n"),
+            Err("`--set` expects `<name>=<value>`, got `row 10`".into())
+        );
+    }
+
+    #[test]
+    fn test_string_literal() {
+        assert_eq!(string_literal(r#""a\nb\"c""#), Some("a\nb\"c".into()));
+        assert_eq!(string_literal("\"1\\n\\\n        2\""), Some("1\n2".into()));
+        assert_eq!(string_literal("r\"x\\n\ny\";"), Some("x\\n\ny".into()));
+        assert_eq!(string_literal("r#\"a\"b\"#"), Some("a\"b".into()));
+        assert_eq!(string_literal("\"open"), None);
+    }
+
+    #[test]
+    fn test_string_constants() {
+        let source = "const LIMIT: usize = 3;
+    const TEST: &str = \"1\n2\";
+    static TEST_INPUT: &'static str = r\"a\";
+    // my_const TEST2: &str = \"no\";";
+        assert_eq!(
+            string_constants(source),
+            vec![
+                Constant {
+                    name: "TEST".into(),
+                    value: "1\n2".into()
+                },
+                Constant {
+                    name: "TEST_INPUT".into(),
+                    value: "a".into()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! The `fetch` command: downloads the puzzle input of a day once.
+//!
+//! The input is requested with the session cookie of the website, taken from
+//! `$AOC_SESSION` or the file `~/.config/aoc/session`. It is stored in the
+//! [`input_dir`](crate::input::input_dir) as `<year>/day<day>`, where the
+//! runner picks it up, and never requested again once it is there.
+//! `$AOC_BASE_URL` replaces the website, e.g. with a local test server.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{input, Workspace};
+
+/// Name of the environment variable holding the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Name of the environment variable replacing [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the website, as asked for by its maintainer.
+pub(crate) const USER_AGENT: &str = "github.com/phideg/AoC aoc runner";
+
+/// Whether the input was downloaded or already there.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of `day` unless it is cached already.
+pub fn fetch(workspace: &Workspace, day: u8) -> Result<bool, String> {
+    match fetch_to(
+        &input::input_dir(),
+        &base_url(),
+        session,
+        workspace.year,
+        day,
+    )? {
+        Fetched::Cached(path) => println!("the input of day {day} is cached in {}", path.display()),
+        Fetched::Downloaded(path) => {
+            println!("downloaded the input of day {day} to {}", path.display());
+        }
+    }
+    Ok(true)
+}
+
+/// Downloads the input of a day into `dir` if it isn't there, the session
+/// is only looked up when needed.
+pub fn fetch_to(
+    dir: &Path,
+    base_url: &str,
+    session: impl FnOnce() -> Result<String, String>,
+    year: u16,
+    day: u8,
+) -> Result<Fetched, String> {
+    let path = input::input_path(dir, year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = download(base_url, &session()?, year, day)?;
+    let parent = path.parent().expect("the input is in a year directory");
+    fs::create_dir_all(parent)
+        .map_err(|err| format!("failed to create {}: {err}", parent.display()))?;
+    // a partial file must not count as cached
+    let partial = path.with_extension("partial");
+    fs::write(&partial, input)
+        .and_then(|()| fs::rename(&partial, &path))
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+fn download(base_url: &str, session: &str, year: u16, day: u8) -> Result<String, String> {
+    let url = format!("{}/{year}/day/{day}/input", base_url.trim_end_matches('/'));
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call();
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| format!("failed to read the input from {url}: {err}")),
+        Err(ureq::Error::Status(404, _)) => Err(format!(
+            "the input of {year} day {day} is not available yet"
+        )),
+        Err(ureq::Error::Status(400 | 500, _)) => Err(format!("{url} rejected the session cookie")),
+        Err(ureq::Error::Status(status, _)) => Err(format!("{url} responded with {status}")),
+        Err(err) => Err(format!("failed to request {url}: {err}")),
+    }
+}
+
+/// The website from [`BASE_URL_VAR`] or the [`DEFAULT_BASE_URL`].
+pub(crate) fn base_url() -> String {
+    env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into())
+}
+
+/// The session cookie from [`SESSION_VAR`] or the config file.
+pub(crate) fn session() -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session.trim().to_owned());
+    }
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|dir| dir.join("aoc").join("session"));
+    config
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| {
+            format!("no session cookie, set ${SESSION_VAR} or write it to ~/.config/aoc/session")
+        })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        process,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::{fetch_to, Fetched};
+
+    /// Serves `responses` in order and records the request line, cookie and
+    /// body of every request.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for ((status, body), stream) in responses.into_iter().zip(listener.incoming()) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request = vec![];
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(value) = line.strip_prefix("Content-Length: ") {
+                        length = value.parse().unwrap();
+                    }
+                    if !line.contains(':') || line.starts_with("Cookie") {
+                        request.push(line.to_owned());
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                if length > 0 {
+                    request.push(String::from_utf8(content).unwrap());
+                }
+                recorded.lock().unwrap().push(request.join("\n"));
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let (url, requests) = serve(vec![(200, "1 2\n3 4\n")]);
+        let session = || Ok("abc".to_owned());
+
+        let path = dir.join("2022").join("day5");
+        assert_eq!(
+            fetch_to(&dir, &url, session, 2022, 5),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        // cached days need neither the server nor a session
+        assert_eq!(
+            fetch_to(&dir, &url, || Err("no session".into()), 2022, 5),
+            Ok(Fetched::Cached(path))
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec!["GET /2022/day/5/input HTTP/1.1\nCookie: session=abc"]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dir = env::temp_dir().join(format!("aoc-fetch-errors-{}", process::id()));
+        let (url, _) = serve(vec![(404, "not yet"), (400, "bad session")]);
+        let session = || Ok("abc".to_owned());
+
+        assert_eq!(
+            fetch_to(&dir, &url, session, 2030, 1),
+            Err("the input of 2030 day 1 is not available yet".into())
+        );
+        assert_eq!(
+            fetch_to(&dir, &url, session, 2030, 1),
+            Err(format!(
+                "{url}/2030/day/1/input rejected the session cookie"
+            ))
+        );
+        assert!(!dir.join("2030").join("day1").exists());
+        assert_eq!(
+            fetch_to(&dir, &url, || Err("no session".into()), 2030, 1),
+            Err("no session".into())
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! Export of grid states as images, for grids too large for a terminal.
+//!
+//! An [`Image`] is made from a [`Grid`] or from the text of any `Display`
+//! state, colored by a function of the cell or with a [`Palette`], and
+//! written as binary PPM or as PNG. The PNG is not compressed, its image data
+//! is stored in plain deflate blocks, so no dependency is needed.
+//!
+//! Like [`record`](crate::record), a day hands its image to [`snapshot`],
+//! which only builds it if the runner was started with `--image <file>`:
+//!
+//! ```
+//! use aoc::image::{Image, Palette};
+//! use grid::Grid;
+//!
+//! let heights: Grid<u8> = "019\n990".parse().unwrap();
+//! aoc::image::snapshot(|| {
+//!     Image::from_grid(&heights, |&h| Palette::HEAT.scale(f64::from(h - b'0'), 9.0)).scaled(8)
+//! });
+//! ```
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use grid::{Grid, Pos};
+
+/// A color as red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// A color gradient through evenly spaced stops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette(&'static [Rgb]);
+
+impl Palette {
+    pub const GRAY: Palette = Palette(&[BLACK, WHITE]);
+    /// Black through red and yellow to white.
+    pub const HEAT: Palette = Palette(&[BLACK, [160, 0, 0], [255, 160, 0], [255, 255, 160]]);
+    /// Water through grass and rock to snow.
+    pub const TERRAIN: Palette = Palette(&[
+        [20, 60, 140],
+        [40, 150, 70],
+        [150, 120, 80],
+        [250, 250, 250],
+    ]);
+
+    pub const fn new(stops: &'static [Rgb]) -> Self {
+        assert!(!stops.is_empty(), "a palette needs a color");
+        Palette(stops)
+    }
+
+    /// The color at `t` between 0 (the first stop) and 1 (the last stop).
+    pub fn at(self, t: f64) -> Rgb {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let position = t * (self.0.len() - 1) as f64;
+        let i = (position as usize).min(self.0.len() - 1);
+        let Some(next) = self.0.get(i + 1) else {
+            return self.0[i];
+        };
+        let fraction = position - i as f64;
+        let mix =
+            |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * fraction).round() as u8;
+        [0, 1, 2].map(|c| mix(self.0[i][c], next[c]))
+    }
+
+    /// The color of `value` on a scale from 0 to `max`.
+    pub fn scale(self, value: f64, max: f64) -> Rgb {
+        self.at(if max == 0.0 { 0.0 } else { value / max })
+    }
+}
+
+/// A picture of `width` times `height` pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per cell of `grid`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut color: impl FnMut(&T) -> Rgb) -> Self {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.values().map(&mut color).collect(),
+        }
+    }
+
+    /// One pixel per character of `text`, as printed by a `Display`. Short
+    /// lines are padded with `background`.
+    pub fn from_text(text: &str, background: Rgb, mut color: impl FnMut(char) -> Rgb) -> Self {
+        let width = text.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut image = Image::new(width, text.lines().count(), background);
+        for (row, line) in text.lines().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                image[Pos::new(row, col)] = color(c);
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every pixel as a square of `factor` pixels.
+    pub fn scaled(&self, factor: usize) -> Self {
+        assert!(factor > 0, "the scale factor must be positive");
+        let mut pixels = Vec::with_capacity(self.pixels.len() * factor * factor);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let scaled: Vec<Rgb> = row
+                .iter()
+                .flat_map(|&pixel| std::iter::repeat_n(pixel, factor))
+                .collect();
+            for _ in 0..factor {
+                pixels.extend_from_slice(&scaled);
+            }
+        }
+        Image {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// Writes the image as binary PPM (`P6`).
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())
+    }
+
+    /// Writes the image as an 8 bit RGB PNG with stored deflate blocks.
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "the image is too large");
+        let mut header = vec![];
+        header.extend(
+            u32::try_from(self.width)
+                .map_err(|_| too_large())?
+                .to_be_bytes(),
+        );
+        header.extend(
+            u32::try_from(self.height)
+                .map_err(|_| too_large())?
+                .to_be_bytes(),
+        );
+        // bit depth 8, truecolor, deflate, no filter, no interlace
+        header.extend([8, 2, 0, 0, 0]);
+        // every scanline starts with the filter type 0, none
+        let mut scanlines = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            scanlines.push(0);
+            scanlines.extend(row.concat());
+        }
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+        write_chunk(&mut out, b"IHDR", &header)?;
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&scanlines))?;
+        write_chunk(&mut out, b"IEND", &[])
+    }
+
+    /// Writes the image to `path` as PNG or PPM, depending on its extension.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut data = vec![];
+        let written = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.write_png(&mut data),
+            Some("ppm") => self.write_ppm(&mut data),
+            _ => {
+                return Err(format!(
+                    "{} is neither a .png nor a .ppm file",
+                    path.display()
+                ))
+            }
+        };
+        written
+            .and_then(|()| fs::write(path, data))
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }
+}
+
+impl std::ops::Index<Pos> for Image {
+    type Output = Rgb;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        assert!(pos.col < self.width, "{pos:?} is outside of the image");
+        &self.pixels[pos.row * self.width + pos.col]
+    }
+}
+
+impl std::ops::IndexMut<Pos> for Image {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        assert!(pos.col < self.width, "{pos:?} is outside of the image");
+        &mut self.pixels[pos.row * self.width + pos.col]
+    }
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the image is too large"))?;
+    out.write_all(&len.to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    out.write_all(&crc32(&[kind.as_slice(), data].concat()).to_be_bytes())
+}
+
+/// A zlib stream of `data` in uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const BLOCK: usize = u16::MAX as usize;
+    let blocks = data.len().div_ceil(BLOCK).max(1);
+    let mut stream = Vec::with_capacity(data.len() + 5 * blocks + 6);
+    // deflate with a 32K window, no preset dictionary, check bits
+    stream.extend([0x78, 0x01]);
+    for i in 0..blocks {
+        let block = &data[i * BLOCK..data.len().min((i + 1) * BLOCK)];
+        stream.push(u8::from(i == blocks - 1));
+        let len = block.len() as u16;
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(u32::MAX, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1_u32, 0_u32), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+/// The image of the running day, `None` inside unless exporting.
+static SNAPSHOT: Mutex<Option<Option<Image>>> = Mutex::new(None);
+
+/// Starts keeping the images handed to [`snapshot`].
+pub fn start() {
+    *SNAPSHOT.lock().unwrap_or_else(|err| err.into_inner()) = Some(None);
+}
+
+/// Stops exporting and returns the last image.
+pub fn finish() -> Option<Image> {
+    SNAPSHOT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .take()
+        .flatten()
+}
+
+/// Builds and keeps the image of the state if exporting was [`start`]ed,
+/// a later image replaces an earlier one.
+pub fn snapshot(image: impl FnOnce() -> Image) {
+    if let Some(snapshot) = SNAPSHOT
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .as_mut()
+    {
+        *snapshot = Some(image());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::{Grid, Pos};
+
+    use super::{adler32, crc32, Image, Palette, BLACK, WHITE};
+
+    const RED: [u8; 3] = [255, 0, 0];
+
+    #[test]
+    fn test_palette() {
+        assert_eq!(Palette::GRAY.at(0.0), BLACK);
+        assert_eq!(Palette::GRAY.at(0.5), [128, 128, 128]);
+        assert_eq!(Palette::GRAY.scale(3.0, 2.0), WHITE);
+        assert_eq!(Palette::HEAT.at(1.0 / 3.0), [160, 0, 0]);
+        assert_eq!(Palette::new(&[RED]).at(0.7), RED);
+    }
+
+    #[test]
+    fn test_from_text() {
+        let image = Image::from_text("#.\n#\n", BLACK, |c| if c == '#' { RED } else { WHITE });
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image[Pos::new(0, 1)], WHITE);
+        assert_eq!(image[Pos::new(1, 0)], RED);
+        assert_eq!(image[Pos::new(1, 1)], BLACK);
+
+        let grid: Grid<u8> = "12\n34".parse().unwrap();
+        let from_grid = Image::from_grid(&grid, |&h| Palette::GRAY.scale(h.into(), 4.0));
+        assert_eq!(from_grid[Pos::new(1, 1)], WHITE);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut image = Image::new(2, 1, BLACK);
+        image[Pos::new(0, 1)] = RED;
+        let mut ppm = vec![];
+        image.scaled(2).write_ppm(&mut ppm).unwrap();
+        assert_eq!(&ppm[..11], b"P6\n4 2\n255\n");
+        let row = [BLACK, BLACK, RED, RED].concat();
+        assert_eq!(&ppm[11..], [row.clone(), row].concat());
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    /// Reads the pixels back from a PNG written by [`Image::write_png`].
+    fn decode_png(png: &[u8]) -> (usize, usize, Vec<u8>) {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let (mut rest, mut size, mut stream) = (&png[8..], (0, 0), vec![]);
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (kind_data, crc) = rest[4..].split_at(4 + len);
+            assert_eq!(
+                crc32(kind_data),
+                u32::from_be_bytes(crc[..4].try_into().unwrap())
+            );
+            let data = &kind_data[4..];
+            match &kind_data[..4] {
+                b"IHDR" => {
+                    let number =
+                        |at: usize| u32::from_be_bytes(data[at..at + 4].try_into().unwrap());
+                    size = (number(0) as usize, number(4) as usize);
+                    assert_eq!(&data[8..], [8, 2, 0, 0, 0]);
+                }
+                b"IDAT" => stream.extend_from_slice(data),
+                b"IEND" => assert!(data.is_empty()),
+                kind => panic!("unexpected chunk {kind:?}"),
+            }
+            rest = &crc[4..];
+        }
+        assert_eq!(stream[..2], [0x78, 0x01]);
+        let (mut blocks, mut inflated) = (&stream[2..], vec![]);
+        loop {
+            let len = u16::from_le_bytes([blocks[1], blocks[2]]);
+            assert_eq!(!len, u16::from_le_bytes([blocks[3], blocks[4]]));
+            inflated.extend_from_slice(&blocks[5..5 + usize::from(len)]);
+            let last = blocks[0] == 1;
+            blocks = &blocks[5 + usize::from(len)..];
+            if last {
+                break;
+            }
+        }
+        assert_eq!(blocks, adler32(&inflated).to_be_bytes());
+        (size.0, size.1, inflated)
+    }
+
+    #[test]
+    fn test_png() {
+        let mut image = Image::new(3, 2, WHITE);
+        image[Pos::new(1, 2)] = RED;
+        let mut png = vec![];
+        image.write_png(&mut png).unwrap();
+        let (width, height, scanlines) = decode_png(&png);
+        assert_eq!((width, height), (3, 2));
+        let rows = [
+            [0].as_slice(),
+            &[WHITE; 3].concat(),
+            &[0],
+            &[WHITE, WHITE, RED].concat(),
+        ];
+        assert_eq!(scanlines, rows.concat());
+
+        // the image data spans several stored blocks
+        let large = Image::new(200, 200, RED);
+        let mut png = vec![];
+        large.write_png(&mut png).unwrap();
+        let (_, _, scanlines) = decode_png(&png);
+        assert_eq!(scanlines.len(), 200 * (1 + 3 * 200));
+    }
+}
@@ -0,0 +1,177 @@
+//! Resolution and loading of the puzzle input.
+//!
+//! The input of a day is looked up in the following order:
+//!
+//! 1. the path given with `--input` (`-` reads from stdin),
+//! 2. `$AOC_INPUT_DIR/<year>/day<day>` if the environment variable is set
+//!    and the file exists,
+//! 3. `<year>/day<day>` in the [`CACHE_DIR`] if `aoc fetch` downloaded it,
+//! 4. the default `puzzle_input` file next to the `Cargo.toml` of the day.
+
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Solution;
+
+/// Name of the environment variable pointing to a directory of inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The `inputs` directory of the repository, ignored by git.
+pub const CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../../inputs");
+
+/// The directory inputs are downloaded to, `$AOC_INPUT_DIR` or the [`CACHE_DIR`].
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map_or_else(|| CACHE_DIR.into(), PathBuf::from)
+}
+
+/// Path of the input of a day in the `dir` of inputs.
+pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{day}"))
+}
+
+/// Expands to the path of the `puzzle_input` file of the calling crate.
+#[macro_export]
+macro_rules! default_input {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input")
+    };
+}
+
+/// Where the input of a day is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The input could not be read from its [`Source`].
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to read input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Determines where the input of `solution` comes from.
+pub fn resolve(solution: &Solution, input: Option<&Path>) -> Source {
+    let input_dirs: Vec<PathBuf> = env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .into_iter()
+        .chain([CACHE_DIR.into()])
+        .collect();
+    resolve_with(
+        input,
+        &input_dirs,
+        (solution.year, solution.day),
+        solution.input,
+        Path::exists,
+    )
+}
+
+/// The first of the `input_dirs` holding the input of the day, the
+/// `default` file if none does.
+fn resolve_with(
+    input: Option<&Path>,
+    input_dirs: &[PathBuf],
+    (year, day): (u16, u8),
+    default: &str,
+    exists: impl Fn(&Path) -> bool,
+) -> Source {
+    match input {
+        Some(path) if path == Path::new("-") => Source::Stdin,
+        Some(path) => Source::File(path.to_owned()),
+        None => input_dirs
+            .iter()
+            .map(|dir| input_path(dir, year, day))
+            .find(|path| exists(path))
+            .map_or_else(|| Source::File(default.into()), Source::File),
+    }
+}
+
+/// Reads the input of `solution`, see [`resolve`].
+pub fn load(solution: &Solution, input: Option<&Path>) -> Result<String, InputError> {
+    let source = resolve(solution, input);
+    let content = match &source {
+        Source::Stdin => {
+            let mut content = String::new();
+            io::stdin().read_to_string(&mut content).map(|_| content)
+        }
+        Source::File(path) => fs::read_to_string(path),
+    };
+    content.map_err(|error| InputError { source, error })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{resolve_with, Source};
+
+    const DAY: (u16, u8) = (2022, 7);
+    const DEFAULT: &str = "day7/puzzle_input";
+
+    #[test]
+    fn test_resolve_explicit_input() {
+        let dirs = [PathBuf::from("inputs")];
+        assert_eq!(
+            resolve_with(Some(Path::new("my/input")), &dirs, DAY, DEFAULT, |_| true),
+            Source::File("my/input".into())
+        );
+        assert_eq!(
+            resolve_with(Some(Path::new("-")), &dirs, DAY, DEFAULT, |_| true),
+            Source::Stdin
+        );
+    }
+
+    #[test]
+    fn test_resolve_input_dir() {
+        let dirs = [PathBuf::from("inputs"), PathBuf::from("cache")];
+        assert_eq!(
+            resolve_with(None, &dirs, DAY, DEFAULT, |_| true),
+            Source::File("inputs/2022/day7".into())
+        );
+        assert_eq!(
+            resolve_with(None, &dirs, DAY, DEFAULT, |path| path.starts_with("cache")),
+            Source::File("cache/2022/day7".into())
+        );
+    }
+
+    #[test]
+    fn test_resolve_default() {
+        assert_eq!(
+            resolve_with(None, &[], DAY, DEFAULT, |_| true),
+            Source::File("day7/puzzle_input".into())
+        );
+        assert_eq!(
+            resolve_with(None, &[PathBuf::from("inputs")], DAY, DEFAULT, |_| false),
+            Source::File("day7/puzzle_input".into())
+        );
+    }
+}
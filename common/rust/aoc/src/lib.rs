@@ -0,0 +1,95 @@
+//! Shared runner for my Advent of Code solutions.
+//!
+//! Every day crate exposes a [`Solution`] which is registered in the `aoc`
+//! binary of its year workspace. The binary can run a single part, a whole
+//! day or all registered days:
+//!
+//! ```text
+//! aoc run --day 15 --part 2 --input path/to/input
+//! aoc run --all
+//! aoc verify
+//! aoc bench --runs 20 --save
+//! aoc new-day --day 16
+//! aoc examples --day 16 --save
+//! aoc stars
+//! aoc fetch --day 16
+//! aoc submit --day 16 --part 1
+//! aoc run --day 14 --part 2 --record cave.cast
+//! aoc play cave.cast --speed 4
+//! aoc run --day 8 --part 2 --image trees.png
+//! aoc run --day 21 --part 2 --set variable=pppw
+//! aoc run --day 21 --part 2 --dot monkeys.dot
+//! ```
+//!
+//! See [`input`] for where the puzzle input is read from and [`answers`] for
+//! the answers checked by `verify`, [`bench`] for the stored timings and
+//! [`examples`] for the examples taken from the puzzle text.
+
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod combinator;
+pub mod cycle;
+pub mod dot;
+pub mod examples;
+pub mod fetch;
+pub mod image;
+pub mod input;
+pub mod memory;
+pub mod num;
+pub mod options;
+pub mod parse;
+pub mod puzzle;
+pub mod readme;
+pub mod record;
+mod runner;
+mod scaffold;
+mod stars;
+pub mod submit;
+pub mod util;
+mod verify;
+
+pub use answer::Answer;
+pub use eyre::{bail, ensure, eyre, Result};
+pub use parse::ParseError;
+pub use puzzle::{Outcome, Puzzle, Run};
+pub use runner::{main, run_day};
+
+/// A year workspace with the solutions registered in its `aoc` binary.
+#[derive(Debug, Clone, Copy)]
+pub struct Workspace {
+    pub year: u16,
+    /// Directory of the workspace, usually `concat!(env!("CARGO_MANIFEST_DIR"), "/..")`.
+    pub root: &'static str,
+    pub solutions: &'static [Solution],
+}
+
+/// The registration of a day's [`Puzzle`].
+///
+/// Parts that are not solved yet are reported as unimplemented and make the
+/// runner exit with a non-zero code.
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    /// Path of the default input file, usually [`default_input!`].
+    pub input: &'static str,
+    solve: fn(&str, &[u8]) -> Run,
+}
+
+impl Solution {
+    pub const fn new<P: Puzzle>(year: u16, day: u8, input: &'static str) -> Self {
+        Solution {
+            year,
+            day,
+            input,
+            solve: puzzle::solve::<P>,
+        }
+    }
+
+    /// Parses `input` and runs the given parts (1 or 2) on it.
+    pub fn solve(&self, input: &str, parts: &[u8]) -> Run {
+        (self.solve)(input, parts)
+    }
+}
@@ -0,0 +1,181 @@
+//! Heap usage of the puzzle steps.
+//!
+//! With the `memory-stats` feature the [`CountingAllocator`] is installed as
+//! global allocator and every step of a puzzle reports its peak heap usage and
+//! number of allocations. Without the feature [`measure`] reports nothing and
+//! the system allocator is used as usual.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(feature = "memory-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator keeping track of the live bytes, their peak and the
+/// number of allocations.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(&self, size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(&self, size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            match new_size.checked_sub(layout.size()) {
+                Some(grown) => self.grow(grown),
+                None => self.shrink(layout.size() - new_size),
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of a single step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Usage {
+    /// Maximum number of bytes allocated by the step at the same time.
+    pub peak: usize,
+    /// Number of allocations and reallocations.
+    pub allocations: usize,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocation{}",
+            Bytes(self.peak),
+            self.allocations,
+            if self.allocations == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// A number of bytes displayed with a binary unit.
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Whether the [`CountingAllocator`] is installed.
+pub const fn enabled() -> bool {
+    cfg!(feature = "memory-stats")
+}
+
+/// Runs `f` and returns its heap usage if the counting allocator is enabled.
+///
+/// The peak only counts the bytes allocated on top of those already live when
+/// `f` starts, so the input of a part is not attributed to it.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !enabled() {
+        return (f(), None);
+    }
+    let (base, allocations) = start();
+    let value = f();
+    (value, Some(stop(base, allocations)))
+}
+
+fn start() -> (usize, usize) {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    (base, ALLOCATIONS.load(Ordering::Relaxed))
+}
+
+fn stop(base: usize, allocations: usize) -> Usage {
+    Usage {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bytes;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(17).to_string(), "17 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(16 << 40).to_string(), "16.0 TiB");
+    }
+
+    // The counters are shared with the global allocator if it is installed.
+    #[cfg(not(feature = "memory-stats"))]
+    #[test]
+    fn test_counting_allocator() {
+        use std::alloc::{GlobalAlloc, Layout};
+
+        use super::{start, stop, CountingAllocator, Usage};
+
+        let layout = Layout::from_size_align(1000, 8).unwrap();
+        let (base, allocations) = start();
+        unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            let ptr = CountingAllocator.realloc(ptr, layout, 3000);
+            CountingAllocator.dealloc(ptr, Layout::from_size_align(3000, 8).unwrap());
+            let ptr = CountingAllocator.alloc_zeroed(layout);
+            CountingAllocator.dealloc(ptr, layout);
+        }
+        assert_eq!(
+            stop(base, allocations),
+            Usage {
+                peak: 3000,
+                allocations: 3
+            }
+        );
+    }
+}
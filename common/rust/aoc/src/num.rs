@@ -0,0 +1,713 @@
+//! Number theory for puzzles whose numbers outgrow the primitive types.
+//!
+//! The helpers on primitive integers are checked: instead of overflowing
+//! they return `None`, so a solution can report the overflow as an error.
+//! [`Rational`] divides exactly and [`BigInt`] takes over where even `i128`
+//! is too small:
+//!
+//! ```
+//! use aoc::num::{crt, lcm_all, BigInt};
+//!
+//! assert_eq!(lcm_all([4_u64, 6, 10]), Some(60));
+//! // x ≡ 2 (mod 4) and x ≡ 4 (mod 6), the moduli don't need to be coprime
+//! assert_eq!(crt([(2, 4), (4, 6)]), Ok((10, 12)));
+//! let big = BigInt::from(u64::MAX).pow(3);
+//! assert_eq!(big.to_string(), "6277101735386680762814942322444851025767571854389858533375");
+//! ```
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+/// The primitive integer types the helpers accept.
+pub trait Integer: Copy + Ord + Div<Output = Self> + Rem<Output = Self> + fmt::Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn saturating_mul(self, other: Self) -> Self;
+    /// The absolute value, `None` for the minimum of a signed type.
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($abs:expr; $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn saturating_mul(self, other: Self) -> Self {
+                <$t>::saturating_mul(self, other)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                let abs: fn($t) -> Option<$t> = $abs;
+                abs(self)
+            }
+        })*
+    };
+}
+
+impl_integer!(Some; u8, u16, u32, u64, u128, usize);
+impl_integer!(|n| n.checked_abs(); i8, i16, i32, i64, i128, isize);
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0.
+///
+/// Panics if the result doesn't fit, only for the minimum of a signed type.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let abs = |n: T| n.checked_abs().expect("the gcd doesn't fit the type");
+    let (mut a, mut b) = (abs(a), abs(b));
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative, `None` if it doesn't fit.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)?.checked_abs()
+}
+
+/// The least common multiple of all `numbers`, 1 if there are none.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// The sum of `numbers`, `None` if it overflows.
+pub fn checked_sum<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ZERO, T::checked_add)
+}
+
+/// The product of `numbers`, `None` if it overflows.
+pub fn checked_product<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ONE, T::checked_mul)
+}
+
+/// The product of `numbers`, the bound of the type if it overflows.
+pub fn saturating_product<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, T::saturating_mul)
+}
+
+/// The gcd `g` of `a` and `b` with `x` and `y` such that `a*x + b*y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a*x ≡ 1 (mod m)`, `None` unless `a` and `m` are
+/// coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    assert!(m > 0, "the modulus must be positive");
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Why [`crt`] has no result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// Two congruences contradict each other.
+    NoSolution,
+    /// The combined modulus doesn't fit an `i128`.
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::NoSolution => write!(f, "the congruences have no common solution"),
+            CrtError::Overflow => write!(f, "the combined modulus overflows"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves the congruences `x ≡ r (mod m)` of the `(r, m)` pairs with the
+/// Chinese remainder theorem. The moduli don't need to be coprime.
+///
+/// Returns the smallest non-negative solution `x` and the lcm `M` of the
+/// moduli, all solutions are `x + k*M`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Result<(i128, i128), CrtError> {
+    congruences.into_iter().try_fold((0, 1), |(x, m), (r, n)| {
+        assert!(n > 0, "the moduli must be positive");
+        // x + m*k ≡ r (mod n) has a solution k if gcd(m, n) divides r - x
+        let (g, p, _) = extended_gcd(m, n);
+        let diff = (r - x).rem_euclid(n);
+        if diff % g != 0 {
+            return Err(CrtError::NoSolution);
+        }
+        let step = n / g;
+        let lcm = m.checked_mul(step).ok_or(CrtError::Overflow)?;
+        let k = mul_mod(diff / g, p.rem_euclid(step), step);
+        let x = x.checked_add(m.checked_mul(k).ok_or(CrtError::Overflow)?);
+        Ok((x.ok_or(CrtError::Overflow)?.rem_euclid(lcm), lcm))
+    })
+}
+
+/// `a * b mod m` for `a` and `b` in `0..m` without overflowing.
+fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    match a.checked_mul(b) {
+        Some(product) => product % m,
+        None => {
+            let product = BigInt::from(a) * BigInt::from(b) % BigInt::from(m);
+            product
+                .to_i128()
+                .expect("the remainder is less than the modulus")
+        }
+    }
+}
+
+/// An exact fraction of `i128`s whose operations are checked: they return
+/// `None` on overflow or division by zero instead of rounding or panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    /// In lowest terms with a positive denominator.
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// `numer / denom` in lowest terms, `None` if `denom` is zero or either
+    /// is `i128::MIN`.
+    pub fn new(numer: i128, denom: i128) -> Option<Self> {
+        if denom == 0 || numer == i128::MIN || denom == i128::MIN {
+            return None;
+        }
+        let g = gcd(numer, denom);
+        let sign = denom.signum();
+        Some(Rational {
+            numer: sign * numer / g,
+            denom: sign * denom / g,
+        })
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    /// The value if it is an integer.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denom == 1).then_some(self.numer)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Rational::new(self.numer.checked_neg()?, self.denom)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let g = gcd(self.denom, other.denom);
+        let numer = self
+            .numer
+            .checked_mul(other.denom / g)?
+            .checked_add(other.numer.checked_mul(self.denom / g)?)?;
+        Rational::new(numer, self.denom.checked_mul(other.denom / g)?)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // cancel crosswise first to keep the products small
+        let g1 = gcd(self.numer, other.denom);
+        let g2 = gcd(other.numer, self.denom);
+        Rational::new(
+            (self.numer / g1).checked_mul(other.numer / g2)?,
+            (self.denom / g2).checked_mul(other.denom / g1)?,
+        )
+    }
+
+    /// `None` also for a division by zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(Rational::new(other.denom, other.numer)?)
+    }
+}
+
+impl From<i128> for Rational {
+    /// Panics for `i128::MIN`, which has no rational counterpart.
+    fn from(value: i128) -> Self {
+        Rational::new(value, 1).expect("i128::MIN is not a rational")
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.denom {
+            1 => write!(f, "{}", self.numer),
+            denom => write!(f, "{}/{denom}", self.numer),
+        }
+    }
+}
+
+/// A signed integer of any size.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    /// Little endian digits in base 2^32, without leading zeros. Zero has
+    /// no digits and is never negative.
+    digits: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> Self {
+        BigInt {
+            negative: false,
+            digits: self.digits.clone(),
+        }
+    }
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let (mut base, mut result) = (self.clone(), BigInt::from(1));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The value as `i128`, `None` if it doesn't fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .digits
+            .iter()
+            .rev()
+            .fold(0_u128, |n, &d| (n << 32) | u128::from(d));
+        if self.negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// The quotient rounded toward zero and the remainder, which has the
+    /// sign of `self` like the operators of the primitive types.
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let (quotient, remainder) = div_rem_magnitude(&self.digits, &divisor.digits);
+        (
+            BigInt::new(self.negative != divisor.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        )
+    }
+
+    fn new(negative: bool, mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        BigInt {
+            negative: negative && !digits.is_empty(),
+            digits,
+        }
+    }
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0_u64;
+    for (i, &digit) in long.iter().enumerate() {
+        let total = u64::from(digit) + u64::from(short.get(i).copied().unwrap_or(0)) + carry;
+        sum.push(total as u32);
+        carry = total >> 32;
+    }
+    if carry > 0 {
+        sum.push(carry as u32);
+    }
+    sum
+}
+
+/// `a - b` for `a >= b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0_i64;
+    for (i, &digit) in a.iter().enumerate() {
+        let mut total = i64::from(digit) - i64::from(b.get(i).copied().unwrap_or(0)) - borrow;
+        borrow = i64::from(total < 0);
+        if total < 0 {
+            total += 1 << 32;
+        }
+        difference.push(total as u32);
+    }
+    difference
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0_u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (j, &y) in b.iter().enumerate() {
+            let total = u64::from(x) * u64::from(y) + u64::from(product[i + j]) + carry;
+            product[i + j] = total as u32;
+            carry = total >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    product
+}
+
+/// Long division bit by bit, which is fast enough for puzzle sizes.
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_magnitude(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    let mut quotient = vec![0_u32; a.len()];
+    let mut remainder: Vec<u32> = vec![];
+    for bit in (0..a.len() * 32).rev() {
+        // remainder = remainder * 2 + the next bit of a
+        let mut carry = (a[bit / 32] >> (bit % 32)) & 1;
+        for digit in &mut remainder {
+            let next = *digit >> 31;
+            *digit = (*digit << 1) | carry;
+            carry = next;
+        }
+        if carry > 0 {
+            remainder.push(carry);
+        }
+        if cmp_magnitude(&remainder, b) != Ordering::Less {
+            remainder = sub_magnitude(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, remainder)
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut magnitude = value.unsigned_abs();
+        let mut digits = vec![];
+        while magnitude > 0 {
+            digits.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        BigInt::new(value < 0, digits)
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($t:ty),*) => {
+        $(impl From<$t> for BigInt {
+            fn from(value: $t) -> Self {
+                BigInt::from(i128::from(value))
+            }
+        })*
+    };
+}
+
+impl_from_primitive!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<usize> for BigInt {
+    fn from(value: usize) -> Self {
+        BigInt::from(value as u64)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.digits, &other.digits),
+            (true, true) => cmp_magnitude(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.digits)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add_magnitude(&self.digits, &other.digits));
+        }
+        match cmp_magnitude(&self.digits, &other.digits) {
+            Ordering::Less => {
+                BigInt::new(other.negative, sub_magnitude(&other.digits, &self.digits))
+            }
+            _ => BigInt::new(self.negative, sub_magnitude(&self.digits, &other.digits)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other.clone()
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_magnitude(&self.digits, &other.digits),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+macro_rules! forward_owned {
+    ($($trait:ident $method:ident),*) => {
+        $(impl $trait for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, other: BigInt) -> BigInt {
+                (&self).$method(&other)
+            }
+        })*
+    };
+}
+
+forward_owned!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        // split into decimal chunks of 9 digits, least significant first
+        let chunk = BigInt::from(1_000_000_000);
+        let mut chunks = vec![];
+        let mut rest = self.abs();
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem(&chunk);
+            chunks.push(remainder.to_i128().expect("a chunk fits"));
+            rest = quotient;
+        }
+        let sign = if self.negative { "-" } else { "" };
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{sign}{}", chunks.next().expect("non-zero has a chunk"))?;
+        chunks.try_for_each(|chunk| write!(f, "{chunk:09}"))
+    }
+}
+
+/// The text is not an integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid integer")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        let ten = BigInt::from(10);
+        let magnitude = digits.bytes().fold(BigInt::zero(), |n, b| {
+            &(&n * &ten) + &BigInt::from(b - b'0')
+        });
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        checked_product, crt, extended_gcd, gcd, lcm, lcm_all, mod_inverse, saturating_product,
+        BigInt, CrtError, Rational,
+    };
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12_u32, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(gcd(0_u8, 0), 0);
+        assert_eq!(lcm(4_i32, -6), Some(12));
+        assert_eq!(lcm(0_u64, 5), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([23_usize, 19, 13, 17]), Some(96577));
+        assert_eq!(lcm_all::<u8>([]), Some(1));
+    }
+
+    #[test]
+    fn test_checked_helpers() {
+        assert_eq!(
+            checked_product([1_000_u32, 1_000, 1_000]),
+            Some(1_000_000_000)
+        );
+        assert_eq!(checked_product([1_000_u32, 1_000, 10_000]), None);
+        assert_eq!(saturating_product([1_000_u16, 1_000]), u16::MAX);
+        assert_eq!(saturating_product([-1_000_i16, 1_000]), i16::MIN);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        let (g, x, y) = extended_gcd(-35, 15);
+        assert_eq!((g, -35 * x + 15 * y), (5, 5));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt([(77, 101), (28, 103)]), Ok((7753, 10403)));
+        // non-coprime moduli
+        assert_eq!(crt([(3, 4), (5, 6)]), Ok((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), Err(CrtError::NoSolution));
+        assert_eq!(crt([]), Ok((0, 1)));
+        // the intermediate products need more than 128 bits
+        let p = (1 << 61) - 1;
+        let q = (1 << 59) - 55;
+        assert_eq!(crt([(p - 1, p), (q - 1, q)]), Ok((p * q - 1, p * q)));
+        assert_eq!(crt([(0, p), (0, q), (0, 1 << 40)]), Err(CrtError::Overflow));
+        let n = (1 << 100) + 1;
+        let (x, m) = crt([(1, 3), ((1 << 99) + 7, n)]).unwrap();
+        assert_eq!((x % 3, x % n, m), (1, (1 << 99) + 7, 3 * n));
+    }
+
+    #[test]
+    fn test_rational() {
+        let r = |n, d| Rational::new(n, d).unwrap();
+        assert_eq!(r(6, -4), r(-3, 2));
+        assert_eq!((r(-3, 2).numer(), r(-3, 2).denom()), (-3, 2));
+        assert_eq!(Rational::new(1, 0), None);
+        assert_eq!(r(1, 6).checked_add(r(1, 3)), Some(r(1, 2)));
+        assert_eq!(r(1, 6).checked_sub(r(1, 3)), Some(r(-1, 6)));
+        assert_eq!(r(2, 3).checked_mul(r(9, 4)), Some(r(3, 2)));
+        assert_eq!(r(2, 3).checked_div(r(-4, 9)), Some(r(-3, 2)));
+        assert_eq!(r(2, 3).checked_div(Rational::ZERO), None);
+        assert_eq!(r(7, 2).to_integer(), None);
+        assert_eq!(r(14, 2).to_integer(), Some(7));
+        assert_eq!(r(-7, 2).to_string(), "-7/2");
+        let max = Rational::from(i128::MAX);
+        assert_eq!(max.checked_add(Rational::ONE), None);
+        assert_eq!(max.checked_mul(r(1, 2)), Some(r(i128::MAX, 2)));
+        assert_eq!(max.checked_neg().unwrap().checked_sub(Rational::ONE), None);
+    }
+
+    #[test]
+    fn test_bigint() {
+        let a: BigInt = "-123456789012345678901234567890".parse().unwrap();
+        let b = BigInt::from(987_654_321_u64);
+        assert_eq!(
+            (&a * &b).to_string(),
+            "-121932631124828532112482853211126352690"
+        );
+        assert_eq!((&a + &b).to_string(), "-123456789012345678900246913569");
+        assert_eq!((&b - &a).to_string(), "123456789012345678902222222211");
+        assert_eq!((&a / &b).to_string(), "-124999998873437499901");
+        assert_eq!((&a % &b).to_string(), "-574845669");
+        assert_eq!(BigInt::from(-7) / BigInt::from(2), BigInt::from(-3));
+        assert_eq!(BigInt::from(-7) % BigInt::from(2), BigInt::from(-1));
+        assert_eq!(BigInt::from(2).pow(128).to_i128(), None);
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert!(BigInt::from(-5) < BigInt::from(3));
+        assert!(a < -BigInt::from(u64::MAX));
+        assert_eq!(-BigInt::zero(), BigInt::zero());
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+    }
+}
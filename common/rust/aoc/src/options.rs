@@ -0,0 +1,57 @@
+//! Options of a day given on the command line.
+//!
+//! `--set <name>=<value>` passes an option to the day being run, which reads
+//! it with [`get`] and falls back to its default otherwise:
+//!
+//! ```text
+//! aoc run --day 21 --part 2 --set variable=pppw
+//! ```
+
+use std::{collections::HashMap, sync::Mutex};
+
+/// The options of the running day and whether the day asked for them.
+static OPTIONS: Mutex<Option<HashMap<String, (String, bool)>>> = Mutex::new(None);
+
+/// Makes `options` available to the days until [`finish`], a later option
+/// overrides an earlier one of the same name.
+pub fn start(options: &[(String, String)]) {
+    let options = options
+        .iter()
+        .map(|(name, value)| (name.clone(), (value.clone(), false)))
+        .collect();
+    *OPTIONS.lock().unwrap_or_else(|err| err.into_inner()) = Some(options);
+}
+
+/// Clears the options and returns the names of those no day asked for.
+pub fn finish() -> Vec<String> {
+    let options = OPTIONS.lock().unwrap_or_else(|err| err.into_inner()).take();
+    let mut unused: Vec<_> = options
+        .into_iter()
+        .flatten()
+        .filter(|(_, (_, used))| !used)
+        .map(|(name, _)| name)
+        .collect();
+    unused.sort();
+    unused
+}
+
+/// The value of the option `name` if it was set.
+pub fn get(name: &str) -> Option<String> {
+    OPTIONS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .as_mut()
+        .and_then(|options| options.get_mut(name))
+        .map(|(value, used)| {
+            *used = true;
+            value.clone()
+        })
+}
+
+/// Splits a `<name>=<value>` argument of `--set`.
+pub(crate) fn parse(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.into(), value.into())),
+        _ => Err(format!("`--set` expects `<name>=<value>`, got `{arg}`")),
+    }
+}
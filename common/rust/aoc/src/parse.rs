@@ -0,0 +1,260 @@
+//! Errors of malformed puzzle input pointing at the offending text.
+//!
+//! Most parsers split the input into slices of it, so the position of an
+//! offending token can be derived from the token itself:
+//!
+//! ```
+//! let input = "1 2\n3 x\n";
+//! let token = &input[6..7];
+//! let err = aoc::parse::number::<u8>(input, token).unwrap_err();
+//! assert_eq!(err.to_string(), "line 2, column 3: expected a number, found `x`");
+//! assert_eq!(
+//!     err.diagnostic(input),
+//!     "error: expected a number, found `x`\n --> line 2, column 3\n  |\n2 | 3 x\n  |   ^\n"
+//! );
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use grid::{Grid, ParseGridError};
+
+/// Malformed input, lines and columns count from one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    /// Column of the first character of `text`.
+    pub column: usize,
+    /// The offending text, empty at the end of the input.
+    pub text: String,
+    /// Description of what should have been found instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `text`, which has to be a slice of `input`.
+    ///
+    /// Falls back to the end of the input if `text` is not part of it.
+    pub fn at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + text.len() <= input.len())
+            .unwrap_or(input.len());
+        Self::at_offset(input, offset, text, expected)
+    }
+
+    /// An error at the byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, text: &str, expected: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Renders the error with the offending line and a caret below the text.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let line = input.lines().nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let first_line = self.text.lines().next().unwrap_or("");
+        let marker = "^".repeat(first_line.chars().count().max(1));
+        format!(
+            "error: {}\n{margin}--> line {}, column {}\n{margin} |\n{number} | {line}\n{margin} | {}{marker}\n",
+            self.message(),
+            self.line,
+            self.column,
+            " ".repeat(self.column - 1),
+        )
+    }
+
+    fn message(&self) -> String {
+        match self.text.as_str() {
+            "" => format!("expected {}, found the end of the input", self.expected),
+            text => format!(
+                "expected {}, found `{}`",
+                self.expected,
+                text.escape_debug()
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses the number `token`, a slice of `input`.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, "a number"))
+}
+
+/// Splits `text`, a slice of `input`, once at `delimiter`.
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, text, format!("`{delimiter}`")))
+}
+
+/// Strips `prefix` from `text`, a slice of `input`.
+pub fn strip_prefix<'a>(input: &str, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let found = text
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(text, |(i, _)| &text[..i]);
+        ParseError::at(input, found, format!("`{prefix}`"))
+    })
+}
+
+/// Parses the character map `text`, a slice of `input`, shorter lines are
+/// padded with `pad` if given, see [`Grid::parse_padded`].
+pub fn grid<T: TryFrom<u8>>(
+    input: &str,
+    text: &str,
+    pad: Option<u8>,
+) -> Result<Grid<T>, ParseError> {
+    let parsed = match pad {
+        Some(pad) => Grid::parse_padded(text, pad),
+        None => text.parse(),
+    };
+    parsed.map_err(|err| {
+        let (line, column, expected) = match err {
+            ParseGridError::InvalidCell { line, column, .. } => (line, column, "a map cell".into()),
+            ParseGridError::Ragged { line, expected, .. } => {
+                (line, 0, format!("a line of {expected} cells"))
+            }
+        };
+        let line = text.lines().nth(line - 1).unwrap_or("");
+        // a single invalid cell or the whole ragged line
+        let token = match line.get(column.saturating_sub(1)..) {
+            Some(rest) if column > 0 => rest.chars().next().map_or("", |c| &rest[..c.len_utf8()]),
+            _ => line,
+        };
+        ParseError::at(input, token, expected)
+    })
+}
+
+/// The text following `text`, a slice of `input`, an empty slice at the end
+/// of `input` if `text` is not part of it.
+pub fn after<'a>(input: &'a str, text: &str) -> &'a str {
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .map(|offset| offset + text.len())
+        .filter(|&end| end <= input.len())
+        .unwrap_or(input.len());
+    &input[offset..]
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::Grid;
+
+    use super::{grid, number, split_once, strip_prefix, ParseError};
+
+    const INPUT: &str = "move 1 from 2 to 3\nmove x from 4 to 5\n";
+
+    #[test]
+    fn test_position() {
+        let err = number::<u8>(INPUT, &INPUT[24..25]).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 2,
+                column: 6,
+                text: "x".into(),
+                expected: "a number".into()
+            }
+        );
+        let err = ParseError::at(INPUT, &INPUT[INPUT.len()..], "a move");
+        assert_eq!((err.line, err.column), (3, 1));
+        // unrelated text is reported at the end of the input
+        let err = ParseError::at(INPUT, "move", "a move");
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let err = ParseError::at(INPUT, &INPUT[19..23], "`step`");
+        assert_eq!(
+            err.diagnostic(INPUT),
+            "\
+error: expected `step`, found `move`
+ --> line 2, column 1
+  |
+2 | move x from 4 to 5
+  | ^^^^
+"
+        );
+        let err = ParseError::at(INPUT, "", "a move");
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a move, found the end of the input"
+        );
+    }
+
+    #[test]
+    fn test_helpers() {
+        let line = &INPUT[..18];
+        assert_eq!(split_once(INPUT, line, " from "), Ok(("move 1", "2 to 3")));
+        assert_eq!(
+            split_once(INPUT, line, ";").unwrap_err().to_string(),
+            "line 1, column 1: expected `;`, found `move 1 from 2 to 3`"
+        );
+        assert_eq!(strip_prefix(INPUT, line, "move "), Ok("1 from 2 to 3"));
+        assert_eq!(
+            strip_prefix(INPUT, line, "jump ").unwrap_err().to_string(),
+            "line 1, column 1: expected `jump `, found `move `"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = "size 3\n\n#.#\n.x.\n##\n";
+        let map = &input[8..];
+        let err = grid::<Cell>(input, map, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 2: expected a map cell, found `x`"
+        );
+        let fixed = input.replace('x', ".");
+        let err = grid::<Cell>(&fixed, &fixed[8..], None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 1: expected a line of 3 cells, found `##`"
+        );
+        let map: Grid<Cell> = grid(&fixed, &fixed[8..], Some(b'.')).unwrap();
+        assert_eq!((map.width(), map.height()), (3, 3));
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct Cell;
+
+    impl TryFrom<u8> for Cell {
+        type Error = ();
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                b'#' | b'.' => Ok(Cell),
+                _ => Err(()),
+            }
+        }
+    }
+}
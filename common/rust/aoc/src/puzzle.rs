@@ -0,0 +1,240 @@
+//! The [`Puzzle`] trait implemented by every day and the type erased
+//! execution of it.
+
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{memory, parse::ParseError, Answer};
+
+/// A day of the Advent of Code.
+///
+/// Parsing is separated from solving, so both parts work on the same parsed
+/// input and every step can be timed and tested on its own.
+pub trait Puzzle {
+    /// The parsed puzzle input, it may borrow from the raw input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> eyre::Result<Self::Input<'_>>;
+
+    fn part1(input: &Self::Input<'_>) -> eyre::Result<impl Into<Answer>>;
+
+    /// Fails with [`Unimplemented`] unless the day has been solved completely.
+    fn part2(_input: &Self::Input<'_>) -> eyre::Result<impl Into<Answer>> {
+        Err::<Answer, _>(Unimplemented.into())
+    }
+}
+
+/// Error of a part that has not been solved yet.
+#[derive(Debug)]
+pub struct Unimplemented;
+
+impl Display for Unimplemented {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+impl std::error::Error for Unimplemented {}
+
+/// Result of running a single step of a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Solved(Answer),
+    Unimplemented,
+    Failed(String),
+    Panicked(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Solved(_))
+    }
+}
+
+/// A part which has been run, see [`Run`].
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Heap usage, if the `memory-stats` feature is enabled.
+    pub memory: Option<memory::Usage>,
+}
+
+/// The timings and outcomes of solving a puzzle.
+#[derive(Debug)]
+pub struct Run {
+    /// Time spent parsing the input.
+    pub parse: Duration,
+    pub parse_memory: Option<memory::Usage>,
+    /// The requested parts or the outcome of parsing if it failed.
+    pub parts: Result<Vec<PartRun>, Outcome>,
+    /// The input line pointed at by a [`ParseError`] of the parser.
+    pub diagnostic: Option<String>,
+}
+
+/// Parses `input` once and runs the requested `parts` of `P` on it.
+pub fn solve<P: Puzzle>(input: &str, parts: &[u8]) -> Run {
+    let start = Instant::now();
+    let mut diagnostic = None;
+    let (parsed, parse_memory) = memory::measure(|| {
+        guarded(|| {
+            P::parse(input).inspect_err(|err| {
+                diagnostic = err
+                    .downcast_ref::<ParseError>()
+                    .map(|err| err.diagnostic(input));
+            })
+        })
+    });
+    let parse = start.elapsed();
+    let input = match parsed {
+        Ok(input) => input,
+        Err(outcome) => {
+            return Run {
+                parse,
+                parse_memory,
+                parts: Err(outcome),
+                diagnostic,
+            }
+        }
+    };
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, memory) = memory::measure(|| match part {
+                1 => guarded(|| P::part1(&input).map(Into::into)),
+                2 => guarded(|| P::part2(&input).map(Into::into)),
+                _ => Err(Outcome::Unimplemented),
+            });
+            PartRun {
+                part,
+                outcome: answer.map_or_else(|o| o, Outcome::Solved),
+                elapsed: start.elapsed(),
+                memory,
+            }
+        })
+        .collect();
+    Run {
+        parse,
+        parse_memory,
+        parts: Ok(parts),
+        diagnostic: None,
+    }
+}
+
+/// Runs `f` and turns errors and panics into the failing [`Outcome`].
+fn guarded<T>(f: impl FnOnce() -> eyre::Result<T>) -> Result<T, Outcome> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) if err.downcast_ref::<Unimplemented>().is_some() => {
+            Err(Outcome::Unimplemented)
+        }
+        Ok(Err(err)) => Err(Outcome::Failed(format!("{err:#}"))),
+        Err(payload) => Err(Outcome::Panicked(
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown reason".into()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, Outcome, Puzzle};
+    use crate::{Answer, ParseError};
+
+    struct Sum;
+
+    impl Puzzle for Sum {
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+            Ok(input.split(',').collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> eyre::Result<impl Into<Answer>> {
+            input
+                .iter()
+                .try_fold(0, |sum, v| Ok(sum + v.parse::<u32>()?))
+        }
+    }
+
+    struct Broken;
+
+    impl Puzzle for Broken {
+        type Input<'a> = &'a str;
+
+        fn parse(input: &str) -> eyre::Result<Self::Input<'_>> {
+            match input {
+                "panic" => panic!("unexpected {input}"),
+                "fail" => eyre::bail!("malformed input"),
+                "1 2 x" => Err(ParseError::at(input, &input[4..], "a number").into()),
+                _ => Ok(input),
+            }
+        }
+
+        fn part1(input: &Self::Input<'_>) -> eyre::Result<impl Into<Answer>> {
+            match input.len() {
+                0 => panic!("malformed input"),
+                len => Ok(len),
+            }
+        }
+    }
+
+    fn outcomes(run: super::Run) -> Result<Vec<Outcome>, Outcome> {
+        run.parts
+            .map(|parts| parts.into_iter().map(|p| p.outcome).collect())
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            outcomes(solve::<Sum>("1,2,3", &[1, 2])),
+            Ok(vec![Outcome::Solved(6.into()), Outcome::Unimplemented])
+        );
+        assert_eq!(
+            outcomes(solve::<Sum>("1,x", &[1])),
+            Ok(vec![Outcome::Failed(
+                "invalid digit found in string".into()
+            )])
+        );
+    }
+
+    #[test]
+    fn test_solve_panics() {
+        assert_eq!(
+            outcomes(solve::<Broken>("", &[1])),
+            Ok(vec![Outcome::Panicked("malformed input".into())])
+        );
+        assert_eq!(
+            outcomes(solve::<Broken>("panic", &[1])),
+            Err(Outcome::Panicked("unexpected panic".into()))
+        );
+        assert_eq!(
+            outcomes(solve::<Broken>("fail", &[1])),
+            Err(Outcome::Failed("malformed input".into()))
+        );
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        let run = solve::<Broken>("1 2 x", &[1]);
+        assert_eq!(
+            run.diagnostic.as_deref(),
+            Some("error: expected a number, found `x`\n --> line 1, column 5\n  |\n1 | 1 2 x\n  |     ^\n")
+        );
+        assert_eq!(
+            outcomes(run),
+            Err(Outcome::Failed(
+                "line 1, column 5: expected a number, found `x`".into()
+            ))
+        );
+        assert_eq!(solve::<Broken>("fail", &[1]).diagnostic, None);
+    }
+}
@@ -0,0 +1,321 @@
+//! Extraction of the examples from the puzzle text in the README of a day.
+//!
+//! The READMEs are the puzzle pages copied as plain text, so the example
+//! blocks are only recognizable by their shape: a run of paragraphs that are
+//! not prose, introduced by a paragraph ending with a colon. The expected
+//! answer of a part is the last number or capitalized word stated right
+//! before the question of the part.
+
+use std::{fs, path::Path};
+
+use crate::Answer;
+
+/// File names of the puzzle text, relative to the crate of a day.
+pub const FILE_NAMES: [&str; 3] = ["README.md", "README.txt", "README"];
+
+/// The example of a part with the answer stated for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<Answer>,
+}
+
+/// What was found in the puzzle text of a day.
+#[derive(Debug, Default, PartialEq)]
+pub struct Readme {
+    /// Every example block of the text, in order.
+    pub blocks: Vec<String>,
+    /// The examples of the parts, part 2 reuses the example of part 1 unless
+    /// it brings its own.
+    pub examples: Vec<Example>,
+}
+
+impl Readme {
+    /// Reads the README of the day crate in `dir`, `None` if there is none.
+    pub fn load(dir: &Path) -> Result<Option<Self>, String> {
+        let Some(path) = FILE_NAMES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.exists())
+        else {
+            return Ok(None);
+        };
+        fs::read_to_string(&path)
+            .map(|text| Some(parse(&text)))
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))
+    }
+
+    /// Whether `text` equals one of the blocks, ignoring surrounding
+    /// whitespace of the lines, which the tests use to indent the examples.
+    pub fn contains(&self, text: &str) -> bool {
+        let trimmed = |text: &str| {
+            let lines: Vec<&str> = text.lines().map(str::trim).collect();
+            lines.join("\n").trim_matches('\n').to_owned()
+        };
+        let text = trimmed(text);
+        self.blocks.iter().any(|block| trimmed(block) == text)
+    }
+}
+
+/// Extracts the examples from the puzzle text.
+pub fn parse(text: &str) -> Readme {
+    let mut readme = Readme::default();
+    let mut part1_input = None;
+    for (part, section) in (1..).zip(sections(text)) {
+        let paragraphs = paragraphs(section);
+        let blocks = blocks(&paragraphs);
+        let statement = statement(&paragraphs);
+        // intermediate states and results are not introduced as examples,
+        // a later larger or smaller example is the one answered last
+        let stated = || {
+            blocks
+                .iter()
+                .filter(|b| statement.is_none_or(|s| b.start < s))
+        };
+        let input = stated()
+            .rfind(|b| b.other_example)
+            .or_else(|| stated().find(|b| b.example))
+            .or(blocks.first().filter(|_| part == 1))
+            .map(|b| b.text.clone())
+            .or_else(|| part1_input.clone());
+        if part == 1 {
+            part1_input.clone_from(&input);
+        }
+        if let Some(input) = input {
+            readme.examples.push(Example {
+                part,
+                input,
+                answer: statement.and_then(|s| answer(&paragraphs[s])),
+            });
+        }
+        readme.blocks.extend(blocks.into_iter().map(|b| b.text));
+    }
+    readme
+}
+
+/// Splits the text at the header of part two, `--- Part Two ---` or the
+/// markdown heading `## Part Two`.
+fn sections(text: &str) -> Vec<&str> {
+    let header = text.match_indices("Part Two").find(|&(at, _)| {
+        let line = &text[text[..at].rfind('\n').map_or(0, |i| i + 1)..at];
+        !line.is_empty() && line.chars().all(|c| matches!(c, '-' | '#' | ' '))
+    });
+    match header {
+        Some((at, _)) => vec![&text[..at], &text[at..]],
+        None => vec![text],
+    }
+}
+
+/// Splits the section at empty lines, fenced code blocks are paragraphs of
+/// their own, empty lines included.
+fn paragraphs(section: &str) -> Vec<Vec<&str>> {
+    let mut paragraphs = vec![];
+    let mut current = vec![];
+    let mut fenced = false;
+    for line in section.lines() {
+        if line.starts_with("```") || (!fenced && line.trim().is_empty()) {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+            fenced ^= line.starts_with("```");
+        } else {
+            current.push(line.trim_end());
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+/// Whether the paragraph is made of sentences rather than puzzle data, a
+/// sentence has at least three words in a row.
+fn is_prose(paragraph: &[&str]) -> bool {
+    let is_word = |word: &str| {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        word.len() > 1 && word.chars().all(|c| c.is_alphabetic() || c == '\'')
+    };
+    let sentence = paragraph.iter().any(|line| {
+        line.split_whitespace()
+            .scan(0, |run, word| {
+                *run = if is_word(word) { *run + 1 } else { 0 };
+                Some(*run)
+            })
+            .any(|run| run >= 3)
+    });
+    let text = paragraph.join(" ");
+    text.starts_with("---") || (sentence && text.trim_end().ends_with(['.', '?', '!', ':', ')']))
+}
+
+/// A run of data paragraphs.
+struct Block {
+    text: String,
+    /// Index of the first paragraph.
+    start: usize,
+    /// Whether the introduction presents it as the example.
+    example: bool,
+    /// Whether the introduction presents a larger or smaller example.
+    other_example: bool,
+}
+
+/// Runs of data paragraphs introduced by a paragraph ending with a colon.
+fn blocks(paragraphs: &[Vec<&str>]) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut i = 0;
+    while i < paragraphs.len() {
+        let intro = i
+            .checked_sub(1)
+            .map(|i| paragraphs[i].join(" ").to_lowercase());
+        if !intro.as_ref().is_some_and(|intro| intro.ends_with(':')) || is_prose(&paragraphs[i]) {
+            i += 1;
+            continue;
+        }
+        let intro = intro.unwrap_or_default();
+        let start = i;
+        let mut block = vec![];
+        while i < paragraphs.len() && !is_prose(&paragraphs[i]) {
+            block.push(paragraphs[i].join("\n"));
+            i += 1;
+        }
+        blocks.push(Block {
+            text: block.join("\n\n"),
+            start,
+            example: intro.contains("for example") || intro.contains("your puzzle input"),
+            other_example: ["larger", "smaller", "complex"]
+                .iter()
+                .any(|size| intro.contains(&format!("{size} example"))),
+        });
+    }
+    blocks
+}
+
+/// Index of the paragraph stating the answer, the one before the question.
+fn statement(paragraphs: &[Vec<&str>]) -> Option<usize> {
+    let question = paragraphs
+        .iter()
+        .rposition(|p| is_prose(p) && p.iter().any(|l| l.contains('?')))?;
+    paragraphs[..question].iter().rposition(|p| is_prose(p))
+}
+
+/// The last number or capitalized word of the statement, calculations in
+/// parentheses are skipped.
+fn answer(statement: &[&str]) -> Option<Answer> {
+    let mut text = String::new();
+    let mut depth = 0_usize;
+    for c in statement.join(" ").chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace()
+        .rev()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric() && c != '-'))
+        .find(|word| is_answer(word))
+        .map(Answer::from)
+}
+
+fn is_answer(word: &str) -> bool {
+    let number = word.strip_prefix('-').unwrap_or(word);
+    (!number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+        || (word.len() > 1 && word.bytes().all(|b| b.is_ascii_uppercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Example};
+    use crate::Answer;
+
+    const README: &str = "--- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of Calories. For example, suppose
+they end up with the following list:
+
+1000
+2000
+
+3000
+
+This list represents the Calories of the food carried by two Elves.
+
+In the example above, this is 3000 (carried by the second Elf).
+
+Find the Elf carrying the most Calories. How many total Calories is that Elf carrying?
+
+To begin, get your puzzle input.
+
+--- Part Two ---
+
+In the example above, the top Elf carries 3000 and the other one 3000 Calories, a total of 6000.
+
+How many Calories are those Elves carrying in total?
+";
+
+    #[test]
+    fn test_parse() {
+        let readme = parse(README);
+        assert_eq!(readme.blocks, vec!["1000\n2000\n\n3000".to_owned()]);
+        assert_eq!(
+            readme.examples,
+            vec![
+                Example {
+                    part: 1,
+                    input: "1000\n2000\n\n3000".into(),
+                    answer: Some(Answer::Number(3000)),
+                },
+                Example {
+                    part: 2,
+                    input: "1000\n2000\n\n3000".into(),
+                    answer: Some(Answer::Number(6000)),
+                },
+            ]
+        );
+        assert!(readme.contains("\n1000  \n    2000\n\n    3000\n"));
+        assert!(!readme.contains("1000\n2000"));
+    }
+
+    #[test]
+    fn test_parse_text_answer() {
+        let readme = parse(
+            "They have a drawing of the stacks:
+
+    [D]
+[N] [C]
+ 1   2
+
+move 1 from 2 to 1
+
+The message is CMZ.
+
+After the rearrangement procedure completes, what crate ends up on top of each stack?
+",
+        );
+        assert_eq!(
+            readme.blocks,
+            vec!["    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1"]
+        );
+        assert_eq!(readme.examples[0].answer, Some(Answer::Text("CMZ".into())));
+    }
+
+    #[test]
+    fn test_parse_fenced() {
+        let readme = parse(
+            "For example:
+
+```text
+1
+
+2
+```
+In the example above, this is 3 (1 + 2).
+
+What is the sum?
+",
+        );
+        assert_eq!(readme.blocks, vec!["1\n\n2"]);
+        assert_eq!(readme.examples[0].answer, Some(Answer::Number(3)));
+    }
+}
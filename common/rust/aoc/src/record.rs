@@ -0,0 +1,295 @@
+//! Recording of simulation states as terminal animations.
+//!
+//! A simulation calls [`frame`] with its state after every step. This does
+//! nothing unless the runner was started with `--record <file>`, then the
+//! [`Display`] of the state is captured by a [`FrameRecorder`] and written
+//! as an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file
+//! once the day is solved. `aoc play <file>` plays it back, as does
+//! `asciinema play <file>`.
+
+use std::{
+    fmt::{Display, Write as _},
+    fs,
+    io::{self, Write},
+    path::Path,
+    sync::Mutex,
+    thread,
+    time::Duration,
+};
+
+/// Time between two frames of a recording made by the runner.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Number of frames a recording made by the runner keeps at most.
+pub const DEFAULT_LIMIT: usize = 1000;
+
+/// Moves the cursor home and clears the screen before every frame.
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// The recorder of the running simulation, if any.
+static RECORDER: Mutex<Option<FrameRecorder>> = Mutex::new(None);
+
+/// Captures the frames of an animation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameRecorder {
+    frames: Vec<String>,
+    interval: Duration,
+    limit: usize,
+}
+
+impl FrameRecorder {
+    /// A recorder of at most `limit` frames shown for `interval` each.
+    pub fn new(interval: Duration, limit: usize) -> Self {
+        assert!(limit > 0, "a recording needs at least one frame");
+        FrameRecorder {
+            frames: vec![],
+            interval,
+            limit,
+        }
+    }
+
+    /// Captures the [`Display`] of `state` as the next frame. Once the limit
+    /// is reached a frame replaces the last one, so the final state is
+    /// always part of the recording.
+    pub fn capture(&mut self, state: &impl Display) {
+        let frame = state.to_string();
+        if self.frames.len() == self.limit {
+            self.frames.pop();
+        }
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Writes the frames as an asciicast v2 file sized to the largest frame.
+    pub fn write_asciicast(&self, mut out: impl Write) -> io::Result<()> {
+        let lines = |frame: &String| frame.lines().count();
+        let width = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.lines())
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = self.frames.iter().map(lines).max().unwrap_or(0);
+        writeln!(
+            out,
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+            width.max(1),
+            height.max(1)
+        )?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            let time = self.interval.as_secs_f64() * i as f64;
+            let data = format!("{CLEAR}{}", frame.replace('\n', "\r\n"));
+            writeln!(out, "[{time:.6}, \"o\", {}]", json_string(&data))?;
+        }
+        Ok(())
+    }
+
+    /// Reads the frames of an asciicast v2 file, every output event is a
+    /// frame and the interval is the time between the first two.
+    pub fn read_asciicast(cast: &str) -> Result<Self, String> {
+        let mut lines = cast
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        match lines.next() {
+            Some((_, header)) if header.contains("\"version\": 2") => {}
+            _ => return Err("1: expected an asciicast v2 header".into()),
+        }
+        let mut times = vec![];
+        let mut frames = vec![];
+        for (i, line) in lines {
+            let event = |msg: &str| format!("{}: {msg}", i + 1);
+            let body = line
+                .trim()
+                .strip_prefix('[')
+                .and_then(|l| l.strip_suffix(']'))
+                .ok_or_else(|| event("expected an event `[time, code, data]`"))?;
+            let (time, rest) = body
+                .split_once(',')
+                .ok_or_else(|| event("expected an event `[time, code, data]`"))?;
+            let time: f64 = time
+                .trim()
+                .parse()
+                .map_err(|_| event(&format!("invalid time `{}`", time.trim())))?;
+            let (code, rest) = parse_json_string(rest.trim_start())
+                .ok_or_else(|| event("expected the event code"))?;
+            if code != "o" {
+                continue;
+            }
+            let data = rest
+                .trim_start()
+                .strip_prefix(',')
+                .and_then(|data| parse_json_string(data.trim_start()))
+                .map(|(data, _)| data)
+                .ok_or_else(|| event("expected the event data"))?;
+            let data = data.strip_prefix(CLEAR).unwrap_or(&data);
+            times.push(time);
+            frames.push(data.replace("\r\n", "\n"));
+        }
+        let interval = match times.as_slice() {
+            [first, second, ..] => Duration::from_secs_f64((second - first).max(0.0)),
+            _ => DEFAULT_INTERVAL,
+        };
+        Ok(FrameRecorder {
+            limit: frames.len().max(1),
+            frames,
+            interval,
+        })
+    }
+
+    /// Shows the frames one after the other, `speed` times as fast as
+    /// recorded.
+    pub fn play(&self, speed: f64, mut out: impl Write) -> io::Result<()> {
+        let pause = self.interval.div_f64(speed);
+        for frame in &self.frames {
+            write!(out, "{CLEAR}{frame}")?;
+            out.flush()?;
+            thread::sleep(pause);
+        }
+        Ok(())
+    }
+}
+
+/// Quotes `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(c));
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The JSON string `s` starts with and the rest of `s`.
+fn parse_json_string(s: &str) -> Option<(String, &str)> {
+    let mut chars = s.strip_prefix('"')?.char_indices();
+    let mut value = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &s[i + 2..])),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'b' => value.push('\u{8}'),
+                'f' => value.push('\u{c}'),
+                'u' => {
+                    let hex: String = (0..4)
+                        .filter_map(|_| chars.next().map(|(_, c)| c))
+                        .collect();
+                    value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Starts capturing the [`frame`]s of the simulations.
+pub fn start(recorder: FrameRecorder) {
+    *RECORDER.lock().unwrap_or_else(|err| err.into_inner()) = Some(recorder);
+}
+
+/// Stops capturing and returns the recorder.
+pub fn finish() -> Option<FrameRecorder> {
+    RECORDER
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .take()
+}
+
+/// Whether a recording was [`start`]ed, to skip preparing frames otherwise.
+pub fn is_recording() -> bool {
+    RECORDER
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .is_some()
+}
+
+/// Captures `state` as the next frame if a recording was [`start`]ed.
+pub fn frame(state: &impl Display) {
+    if let Some(recorder) = RECORDER
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .as_mut()
+    {
+        recorder.capture(state);
+    }
+}
+
+/// Writes the recording to `path`.
+pub fn save(recorder: &FrameRecorder, path: &Path) -> Result<(), String> {
+    let mut cast = vec![];
+    recorder
+        .write_asciicast(&mut cast)
+        .and_then(|()| fs::write(path, cast))
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+/// Plays the recording in `path` back, `speed` times as fast as recorded.
+pub fn play(path: &Path, speed: f64) -> Result<bool, String> {
+    let cast = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let recorder =
+        FrameRecorder::read_asciicast(&cast).map_err(|err| format!("{}:{err}", path.display()))?;
+    recorder
+        .play(speed, io::stdout().lock())
+        .map_err(|err| format!("failed to play {}: {err}", path.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::FrameRecorder;
+
+    #[test]
+    fn test_asciicast() {
+        let mut recorder = FrameRecorder::new(Duration::from_millis(250), 3);
+        for frame in ["#.\n..\n", "\"x\"\n", "..\n.#\n", "\\\u{1b}\n"] {
+            recorder.capture(&frame);
+        }
+        // the last frame replaced the third one
+        assert_eq!(recorder.frames(), ["#.\n..\n", "\"x\"\n", "\\\u{1b}\n"]);
+
+        let mut cast = vec![];
+        recorder.write_asciicast(&mut cast).unwrap();
+        let cast = String::from_utf8(cast).unwrap();
+        assert_eq!(
+            cast,
+            r#"{"version": 2, "width": 3, "height": 2}
+[0.000000, "o", "\u001b[H\u001b[2J#.\r\n..\r\n"]
+[0.250000, "o", "\u001b[H\u001b[2J\"x\"\r\n"]
+[0.500000, "o", "\u001b[H\u001b[2J\\\u001b\r\n"]
+"#
+        );
+        assert_eq!(FrameRecorder::read_asciicast(&cast), Ok(recorder));
+    }
+
+    #[test]
+    fn test_read_asciicast_errors() {
+        assert!(FrameRecorder::read_asciicast("[0.1, \"o\", \"x\"]").is_err());
+        assert_eq!(
+            FrameRecorder::read_asciicast("{\"version\": 2}\n[x, \"o\", \"x\"]"),
+            Err("2: invalid time `x`".into())
+        );
+    }
+}
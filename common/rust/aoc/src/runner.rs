@@ -0,0 +1,213 @@
+use std::{env, process::ExitCode};
+
+use crate::{
+    bench,
+    cli::{self, Command, RunArgs},
+    dot, examples, fetch, image, input, options,
+    record::{self, FrameRecorder},
+    scaffold, stars, submit, verify, Outcome, Solution, Workspace,
+};
+
+/// Entry point of the `aoc` binary of a year workspace.
+pub fn main(workspace: &Workspace) -> ExitCode {
+    match cli::parse_command(env::args().skip(1)) {
+        Ok(Command::Run(args)) => run(workspace.solutions, &args),
+        Ok(Command::Verify { day }) => exit_code(verify::verify(workspace, day)),
+        Ok(Command::Bench(args)) => exit_code(bench::bench(workspace, &args)),
+        Ok(Command::NewDay { day }) => exit_code(scaffold::new_day(workspace, day)),
+        Ok(Command::Examples { day, save }) => exit_code(examples::examples(workspace, day, save)),
+        Ok(Command::Fetch { day }) => exit_code(fetch::fetch(workspace, day)),
+        Ok(Command::Submit { day, part, answer }) => {
+            exit_code(submit::submit(workspace, day, part, answer.as_deref()))
+        }
+        Ok(Command::Play { path, speed }) => exit_code(record::play(&path, speed)),
+        Ok(Command::Stars) => exit_code(stars::stars(workspace)),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn exit_code(result: Result<bool, String>) -> ExitCode {
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Entry point of the binary of a single day.
+pub fn run_day(solution: &Solution) -> ExitCode {
+    match cli::parse_run_args(env::args().skip(1)) {
+        Ok(RunArgs {
+            day: None,
+            all: false,
+            part,
+            input,
+            record,
+            image,
+            dot,
+            options,
+        }) => run(
+            &[*solution],
+            &RunArgs {
+                day: Some(solution.day),
+                part,
+                input,
+                all: false,
+                record,
+                image,
+                dot,
+                options,
+            },
+        ),
+        Ok(_) => {
+            eprintln!("error: `--day` and `--all` are only supported by the `aoc` binary");
+            ExitCode::from(2)
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::DAY_USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(solutions: &[Solution], args: &RunArgs) -> ExitCode {
+    let days = if args.all {
+        solutions.iter().collect::<Vec<_>>()
+    } else {
+        let day = args.day.expect("either a day or all days are requested");
+        match solutions.iter().find(|s| s.day == day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("day {day} is not implemented");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let parts = args.part.map_or(vec![1, 2], |p| vec![p]);
+    if args.record.is_some() {
+        record::start(FrameRecorder::new(
+            record::DEFAULT_INTERVAL,
+            record::DEFAULT_LIMIT,
+        ));
+    }
+    if args.image.is_some() {
+        image::start();
+    }
+    if args.dot.is_some() {
+        dot::start();
+    }
+    options::start(&args.options);
+    let mut failed = false;
+    for solution in days {
+        let input = match input::load(solution, args.input.as_deref()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("day {}: {err}", solution.day);
+                failed = true;
+                continue;
+            }
+        };
+        let run = solution.solve(&input, &parts);
+        match run.parts {
+            Ok(parts) => {
+                if let Some(usage) = run.parse_memory {
+                    eprintln!("day {} input: {:.1?}, {usage}", solution.day, run.parse);
+                }
+                for part in parts {
+                    let step = format!("part {}", part.part);
+                    report(solution.day, &step, &part.outcome);
+                    if let Some(usage) = part.memory {
+                        eprintln!("day {} {step}: {:.1?}, {usage}", solution.day, part.elapsed);
+                    }
+                    failed |= part.outcome.is_failure();
+                }
+            }
+            Err(outcome) => {
+                match run.diagnostic {
+                    Some(diagnostic) => {
+                        eprint!("day {} input: failed to parse\n{diagnostic}", solution.day)
+                    }
+                    None => report(solution.day, "input", &outcome),
+                }
+                failed = true;
+            }
+        }
+    }
+    for name in options::finish() {
+        eprintln!("warning: the option `{name}` was not used");
+    }
+    if let (Some(path), Some(recorder)) = (&args.record, record::finish()) {
+        if recorder.frames().is_empty() {
+            eprintln!("no frames recorded, the day doesn't support `--record`");
+            failed = true;
+        } else if let Err(err) = record::save(&recorder, path) {
+            eprintln!("error: {err}");
+            failed = true;
+        } else {
+            eprintln!(
+                "recorded {} frames to {}",
+                recorder.frames().len(),
+                path.display()
+            );
+        }
+    }
+    if let Some(path) = &args.image {
+        match image::finish() {
+            None => {
+                eprintln!("no image taken, the day doesn't support `--image`");
+                failed = true;
+            }
+            Some(image) => match image.save(path) {
+                Ok(()) => eprintln!(
+                    "saved a {}x{} image to {}",
+                    image.width(),
+                    image.height(),
+                    path.display()
+                ),
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    failed = true;
+                }
+            },
+        }
+    }
+    if let Some(path) = &args.dot {
+        match dot::finish() {
+            None => {
+                eprintln!("no graph built, the day doesn't support `--dot`");
+                failed = true;
+            }
+            Some(graph) => match graph.save(path) {
+                Ok(()) => eprintln!("saved the graph to {}", path.display()),
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    failed = true;
+                }
+            },
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn report(day: u8, step: &str, outcome: &Outcome) {
+    match outcome {
+        Outcome::Solved(answer) => println!("day {day} {step}: {answer}"),
+        Outcome::Unimplemented => eprintln!("day {day} {step}: not implemented"),
+        Outcome::Failed(err) => eprintln!("day {day} {step}: failed: {err}"),
+        Outcome::Panicked(msg) => eprintln!("day {day} {step}: panicked: {msg}"),
+    }
+}
@@ -0,0 +1,302 @@
+//! The `new-day` command creating and registering the crate of a day.
+//!
+//! A new day gets a crate from the templates in `template/`, with an empty
+//! `puzzle_input` and a README pointing to the puzzle. It is added to the
+//! `members` of the workspace and to the dependencies and solutions of the
+//! `aoc` binary, all kept sorted by day.
+
+use std::{fs, path::Path};
+
+use crate::Workspace;
+
+const CARGO_TOML: &str = include_str!("../template/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../template/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../template/main.rs.tmpl");
+const README: &str = include_str!("../template/README.txt.tmpl");
+
+/// Creates the crate of `day` in the `workspace`, existing days are never
+/// overwritten.
+pub fn new_day(workspace: &Workspace, day: u8) -> Result<bool, String> {
+    let root = Path::new(workspace.root);
+    let dir = root.join(format!("day{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| format!("failed to read {}: {err}", path.display()))
+    };
+    let manifest_path = root.join("Cargo.toml");
+    let runner_path = root.join("aoc").join("Cargo.toml");
+    let main_path = root.join("aoc").join("src").join("main.rs");
+    // compute every edit before touching the workspace
+    let runner = read(&runner_path)?;
+    let edition = edition(&runner).unwrap_or("2021");
+    let manifest = add_member(&read(&manifest_path)?, day)?;
+    let runner = add_dependency(&runner, day)?;
+    let main = add_solution(&read(&main_path)?, day)?;
+
+    let render = |template: &str| {
+        template
+            .replace("{{year}}", &workspace.year.to_string())
+            .replace("{{day}}", &day.to_string())
+            .replace("{{edition}}", edition)
+    };
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TOML)),
+        (dir.join("src").join("lib.rs"), render(LIB_RS)),
+        (dir.join("src").join("main.rs"), render(MAIN_RS)),
+        (dir.join("README.txt"), render(README)),
+        (dir.join("puzzle_input"), String::new()),
+        (manifest_path, manifest),
+        (runner_path, runner),
+        (main_path, main),
+    ];
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+    for (path, contents) in files {
+        fs::write(&path, contents)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    println!(
+        "created {} and registered day {day} in the workspace",
+        dir.display()
+    );
+    Ok(true)
+}
+
+/// Sort key of workspace members and dependencies, other crates go first.
+fn day_key(name: &str) -> (u8, String) {
+    let day = name
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .unwrap_or(0);
+    (day, name.to_owned())
+}
+
+fn edition(manifest: &str) -> Option<&str> {
+    manifest.lines().find_map(|line| {
+        let value = line
+            .strip_prefix("edition")?
+            .trim_start()
+            .strip_prefix('=')?;
+        Some(value.trim().trim_matches('"'))
+    })
+}
+
+/// Inserts `day<day>` into the `members` array, keeping its layout.
+fn add_member(manifest: &str, day: u8) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("the workspace has no `members`")?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unterminated `members` array")?;
+    let name = format!("day{day}");
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+    if members.contains(&name.as_str()) {
+        return Err(format!("`{name}` is already a member of the workspace"));
+    }
+    members.push(&name);
+    members.sort_by_key(|m| day_key(m));
+    let quoted = members.iter().map(|m| format!("\"{m}\""));
+    let list = if manifest[start..end].contains('\n') {
+        quoted.map(|m| format!("\n    {m},")).collect::<String>() + "\n"
+    } else {
+        quoted.collect::<Vec<_>>().join(", ")
+    };
+    Ok(format!("{}{list}{}", &manifest[..start], &manifest[end..]))
+}
+
+/// Adds the path dependency on the day to the manifest of the `aoc` binary.
+fn add_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let name = format!("day{day}");
+    let line = format!("{name} = {{ path = \"../{name}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let dependencies = lines
+        .iter()
+        .position(|l| l.trim() == "[dependencies]")
+        .ok_or("the runner has no `[dependencies]`")?;
+    let section_end = lines[dependencies + 1..]
+        .iter()
+        .position(|l| l.starts_with('['))
+        .map_or(lines.len(), |i| dependencies + 1 + i);
+    let mut insert_at = section_end;
+    while insert_at > dependencies + 1 && lines[insert_at - 1].trim().is_empty() {
+        insert_at -= 1;
+    }
+    for (i, existing) in lines
+        .iter()
+        .enumerate()
+        .take(section_end)
+        .skip(dependencies + 1)
+    {
+        let Some((key, _)) = existing.split_once(" = ") else {
+            continue;
+        };
+        if key == name {
+            return Err(format!("the runner already depends on `{name}`"));
+        }
+        if key.starts_with("day") && day_key(key) > day_key(&name) {
+            insert_at = i;
+            break;
+        }
+    }
+    lines.insert(insert_at, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Registers the solution of the day in the `solutions` of the runner.
+fn add_solution(main: &str, day: u8) -> Result<String, String> {
+    let start = main
+        .find("solutions: &[")
+        .ok_or("the runner has no `solutions`")?;
+    let list_start = start + "solutions: &[".len();
+    let end = list_start
+        + main[list_start..]
+            .find(']')
+            .ok_or("unterminated `solutions` array")?;
+    let entry = format!("day{day}::SOLUTION");
+    let mut solutions: Vec<&str> = main[list_start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    if solutions.contains(&entry.as_str()) {
+        return Err(format!("day {day} is already registered"));
+    }
+    solutions.push(&entry);
+    solutions.sort_by_key(|s| day_key(s.trim_end_matches("::SOLUTION")));
+    let indent = main[..start].rsplit('\n').next().unwrap_or("");
+    let one_line = solutions.join(", ");
+    // the layout rustfmt picks for short and long arrays
+    let list = if one_line.len() <= 60 {
+        one_line
+    } else {
+        solutions
+            .iter()
+            .map(|s| format!("\n{indent}    {s},"))
+            .collect::<String>()
+            + "\n"
+            + indent
+    };
+    Ok(format!("{}{list}{}", &main[..list_start], &main[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{add_dependency, add_member, add_solution, edition, new_day};
+    use crate::Workspace;
+
+    #[test]
+    fn test_add_member() {
+        let single = "[workspace]\nmembers = [\"aoc\", \"day1\", \"day14\", \"day2\"]\n";
+        assert_eq!(
+            add_member(single, 3).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"day1\", \"day2\", \"day3\", \"day14\"]\n"
+        );
+        let multi = "members = [\n    \"aoc\",\n    \"day9\",\n]\n\n[workspace.package]\n";
+        assert_eq!(
+            add_member(multi, 10).unwrap(),
+            "members = [\n    \"aoc\",\n    \"day9\",\n    \"day10\",\n]\n\n[workspace.package]\n"
+        );
+        assert!(add_member(multi, 9).is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[features]\nx = []\n\n[dependencies]\naoc.workspace = true\nday1 = { path = \"../day1\" }\nday14 = { path = \"../day14\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 3).unwrap(),
+            "[features]\nx = []\n\n[dependencies]\naoc.workspace = true\nday1 = { path = \"../day1\" }\nday3 = { path = \"../day3\" }\nday14 = { path = \"../day14\" }\n"
+        );
+        assert!(add_dependency(manifest, 20)
+            .unwrap()
+            .ends_with("day14 = { path = \"../day14\" }\nday20 = { path = \"../day20\" }\n"));
+        assert!(add_dependency(manifest, 14).is_err());
+        assert_eq!(edition("[package]\nedition = \"2024\"\n"), Some("2024"));
+    }
+
+    #[test]
+    fn test_add_solution() {
+        let main =
+            "    aoc::main(&aoc::Workspace {\n        solutions: &[day1::SOLUTION],\n    })\n";
+        let main = add_solution(main, 12).unwrap();
+        assert_eq!(
+            main,
+            "    aoc::main(&aoc::Workspace {\n        solutions: &[day1::SOLUTION, day12::SOLUTION],\n    })\n"
+        );
+        let main = add_solution(&main, 2).unwrap();
+        let main = add_solution(&main, 5).unwrap();
+        assert_eq!(
+            main,
+            "    aoc::main(&aoc::Workspace {
+        solutions: &[
+            day1::SOLUTION,
+            day2::SOLUTION,
+            day5::SOLUTION,
+            day12::SOLUTION,
+        ],
+    })
+"
+        );
+        assert!(add_solution(&main, 5).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc-new-day-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "members = [\"aoc\", \"day1\"]\n").unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[package]\nedition = \"2024\"\n\n[dependencies]\naoc.workspace = true\nday1 = { path = \"../day1\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/main.rs"),
+            "        solutions: &[day1::SOLUTION],\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("day1")).unwrap();
+        let workspace = Workspace {
+            year: 2030,
+            root: root.to_str().unwrap().to_owned().leak(),
+            solutions: &[],
+        };
+
+        assert_eq!(new_day(&workspace, 7), Ok(true));
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(
+            read("Cargo.toml"),
+            "members = [\"aoc\", \"day1\", \"day7\"]\n"
+        );
+        assert!(read("aoc/Cargo.toml").ends_with("day7 = { path = \"../day7\" }\n"));
+        assert_eq!(
+            read("aoc/src/main.rs"),
+            "        solutions: &[day1::SOLUTION, day7::SOLUTION],\n"
+        );
+        assert!(read("day7/Cargo.toml")
+            .contains("name = \"day7\"\nversion = \"0.1.0\"\nedition = \"2024\""));
+        assert!(read("day7/src/lib.rs").contains("new::<Day7>(2030, 7, "));
+        assert!(read("day7/README.txt").contains("https://adventofcode.com/2030/day/7"));
+        assert_eq!(read("day7/puzzle_input"), "");
+
+        // existing days are left alone
+        fs::write(root.join("day7/puzzle_input"), "1 2 3").unwrap();
+        assert!(new_day(&workspace, 7)
+            .unwrap_err()
+            .ends_with("day7 already exists"));
+        assert!(new_day(&workspace, 1).is_err());
+        assert_eq!(read("day7/puzzle_input"), "1 2 3");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
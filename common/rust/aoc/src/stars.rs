@@ -0,0 +1,363 @@
+//! The `stars` command: the progress of all year workspaces as an advent
+//! calendar.
+//!
+//! The year workspaces are the `<year>/rust` directories next to the one of
+//! the running binary. Their sources are only scanned, not built, so a part
+//! counts as solved if the [`Puzzle`](crate::Puzzle) of its day implements it
+//! with something else than a placeholder. An implemented part with a recorded
+//! answer is verified; the parts of the running workspace are run to check
+//! that they still give that answer, other years rely on their own `verify`:
+//!
+//! ```text
+//! year   1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25  stars
+//! 2023  ** ** ** *o oo oo oo oo oo oo oo oo oo oo oo oo oo oo oo oo oo oo oo oo ..   7/50
+//! ```
+
+use std::{
+    collections::HashMap,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{self, Answers},
+    input, Outcome, Workspace,
+};
+
+/// Progress of a single part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    /// There is no crate for the day.
+    Missing,
+    /// The crate or the part is a placeholder.
+    Stub,
+    /// The part is implemented but has no recorded answer.
+    Unverified,
+    /// The part is implemented and gives its recorded answer.
+    Verified,
+    /// The part is implemented but no longer gives its recorded answer.
+    Wrong,
+}
+
+impl Status {
+    fn symbol(self) -> char {
+        match self {
+            Status::Missing => '.',
+            Status::Stub => 'o',
+            Status::Unverified => '+',
+            Status::Verified => '*',
+            Status::Wrong => 'x',
+        }
+    }
+}
+
+struct Year {
+    year: u16,
+    /// The status of both parts of every day.
+    days: Vec<[Status; 2]>,
+}
+
+/// Number of puzzles of a year, the calendar was shortened in 2025.
+fn days_of(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Prints the progress of every year workspace of the repository.
+pub fn stars(workspace: &Workspace) -> Result<bool, String> {
+    // the workspace root is `<repository>/<year>/rust`
+    let repository = Path::new(workspace.root).join("../..");
+    let mut years = vec![];
+    for (year, dir) in year_workspaces(&repository)? {
+        let answers = Answers::load(&dir.join(answers::FILE_NAME))?;
+        let days = (1..=days_of(year))
+            .map(|day| {
+                let crate_dir = dir.join(format!("day{day}"));
+                let implemented = implemented_parts(&crate_dir)?;
+                let checked = if year == workspace.year {
+                    check_answers(workspace, &answers, day, implemented)
+                } else {
+                    HashMap::new()
+                };
+                Ok([1, 2].map(|part| {
+                    let recorded = answers.get(day, part).is_some();
+                    status(implemented, part, recorded, checked.get(&part).copied())
+                }))
+            })
+            .collect::<Result<_, String>>()?;
+        years.push(Year { year, days });
+    }
+    print_calendar(&years);
+    Ok(true)
+}
+
+/// The status of `part` of a day, `matches` tells whether the part gave its
+/// recorded answer if it was run.
+fn status(
+    implemented: Option<[bool; 2]>,
+    part: u8,
+    recorded: bool,
+    matches: Option<bool>,
+) -> Status {
+    match implemented {
+        None => Status::Missing,
+        Some(parts) if !parts[usize::from(part - 1)] => Status::Stub,
+        Some(_) if !recorded => Status::Unverified,
+        Some(_) if matches == Some(false) => Status::Wrong,
+        Some(_) => Status::Verified,
+    }
+}
+
+/// Runs the implemented parts of `day` with a recorded answer in the running
+/// `workspace`, whether each gave its answer.
+fn check_answers(
+    workspace: &Workspace,
+    answers: &Answers,
+    day: u8,
+    implemented: Option<[bool; 2]>,
+) -> HashMap<u8, bool> {
+    let Some(solution) = workspace.solutions.iter().find(|s| s.day == day) else {
+        return HashMap::new();
+    };
+    let parts: Vec<u8> = [1, 2]
+        .into_iter()
+        .filter(|&part| implemented.is_some_and(|parts| parts[usize::from(part - 1)]))
+        .filter(|&part| answers.get(day, part).is_some())
+        .collect();
+    if parts.is_empty() {
+        return HashMap::new();
+    }
+    let Ok(input) = input::load(solution, None) else {
+        return parts.into_iter().map(|part| (part, false)).collect();
+    };
+    match solution.solve(&input, &parts).parts {
+        Ok(runs) => runs
+            .into_iter()
+            .map(|run| {
+                let matches = matches!(
+                    (&run.outcome, answers.get(day, run.part)),
+                    (Outcome::Solved(answer), Some(expected)) if answer == expected
+                );
+                (run.part, matches)
+            })
+            .collect(),
+        Err(_) => parts.into_iter().map(|part| (part, false)).collect(),
+    }
+}
+
+/// The `<year>/rust` directories of the repository, ordered by year.
+fn year_workspaces(repository: &Path) -> Result<Vec<(u16, PathBuf)>, String> {
+    let entries = fs::read_dir(repository)
+        .map_err(|err| format!("failed to read {}: {err}", repository.display()))?;
+    let mut years: Vec<(u16, PathBuf)> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let year = entry.file_name().to_str()?.parse().ok()?;
+            let dir = entry.path().join("rust");
+            dir.join("Cargo.toml").exists().then_some((year, dir))
+        })
+        .collect();
+    years.sort();
+    Ok(years)
+}
+
+/// Which parts the crate of a day implements, `None` if there is no crate.
+fn implemented_parts(dir: &Path) -> Result<Option<[bool; 2]>, String> {
+    if !dir.join("Cargo.toml").exists() {
+        return Ok(None);
+    }
+    let read = |name: &str| {
+        let path = dir.join("src").join(name);
+        match fs::read_to_string(&path) {
+            Ok(source) => Ok(Some(source)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+        }
+    };
+    if let Some(lib) = read("lib.rs")? {
+        if lib.contains("Puzzle for") {
+            return Ok(Some([1, 2].map(|part| implements(&lib, part))));
+        }
+    }
+    // a solution predating the runner is a standalone binary
+    let solved = read("main.rs")?.is_some_and(|main| !is_hello_world(&main));
+    Ok(Some([solved; 2]))
+}
+
+fn is_hello_world(main: &str) -> bool {
+    let code = strip_comments(main);
+    code.contains("Hello, world!") && code.matches(';').count() <= 1
+}
+
+/// Whether the `Puzzle` impl of `source` has a `part` which is not a
+/// placeholder, including the function of the same name it delegates to.
+fn implements(source: &str, part: u8) -> bool {
+    let source = strip_comments(source);
+    let Some(impl_range) = source
+        .find("Puzzle for")
+        .and_then(|start| block(&source, start))
+    else {
+        return false;
+    };
+    let name = format!("fn part{part}(");
+    let mut functions = source
+        .match_indices(&name)
+        .filter_map(|(at, _)| block(&source, at).map(|body| (impl_range.contains(&at), body)));
+    let Some((_, body)) = functions.clone().find(|&(in_impl, _)| in_impl) else {
+        return false;
+    };
+    !is_placeholder(&source[body])
+        && !functions.any(|(in_impl, body)| !in_impl && is_placeholder(&source[body]))
+}
+
+/// Whether a function body only signals that it is not solved yet.
+fn is_placeholder(body: &str) -> bool {
+    let code: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+    ["todo!", "unimplemented!", "Unimplemented"]
+        .iter()
+        .any(|marker| code.contains(marker))
+        || matches!(code.as_str(), "{0}" | "{Ok(0)}")
+}
+
+/// The range of the first `{` after `start` up to its matching `}`.
+fn block(source: &str, start: usize) -> Option<Range<usize>> {
+    let open = start + source[start..].find('{')?;
+    let mut depth = 0;
+    for (i, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open..open + i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Removes `//` comments, so commented out code doesn't count.
+fn strip_comments(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| line.find("//").map_or(line, |at| &line[..at]))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn print_calendar(years: &[Year]) {
+    let width = years.iter().map(|y| y.days.len()).max().unwrap_or(0);
+    let header: String = (1..=width).map(|day| format!(" {day:>2}")).collect();
+    println!("year {header}  stars");
+    for year in years {
+        let days: String = year
+            .days
+            .iter()
+            .map(|parts| format!(" {}{}", parts[0].symbol(), parts[1].symbol()))
+            .collect();
+        let stars = year
+            .days
+            .iter()
+            .flatten()
+            .filter(|&&s| s == Status::Verified)
+            .count();
+        println!(
+            "{} {days:<pad$}  {stars:>2}/{}",
+            year.year,
+            year.days.len() * 2,
+            pad = width * 3
+        );
+    }
+    println!(
+        "\n{} verified  {} wrong  {} unverified  {} stub  {} missing",
+        Status::Verified.symbol(),
+        Status::Wrong.symbol(),
+        Status::Unverified.symbol(),
+        Status::Stub.symbol(),
+        Status::Missing.symbol()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{implements, is_hello_world, status, Status};
+
+    const SOURCE: &str = "fn part1(input: &str) -> usize {
+    input.len()
+}
+
+fn part2(_input: &str) -> usize {
+    todo!()
+}
+
+impl aoc::Puzzle for Day1 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoc::Result<impl Into<aoc::Answer>> {
+        Ok(part2(input))
+    }
+}
+";
+
+    #[test]
+    fn test_implements() {
+        assert!(implements(SOURCE, 1));
+        // delegates to a `todo!()`
+        assert!(!implements(SOURCE, 2));
+        let start = SOURCE.find("\n\n    fn part2(input").unwrap();
+        let without_part2 = format!("{}\n}}\n", &SOURCE[..start]);
+        assert!(implements(&without_part2, 1));
+        assert!(!implements(&without_part2, 2));
+        let zero = SOURCE.replace("Ok(part2(input))", "Ok(0)");
+        assert!(!implements(&zero, 2));
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(status(None, 1, true, None), Status::Missing);
+        // a recorded answer doesn't make a placeholder solved
+        assert_eq!(
+            status(Some([false, false]), 1, true, Some(true)),
+            Status::Stub
+        );
+        assert_eq!(status(Some([true, false]), 2, true, None), Status::Stub);
+        assert_eq!(
+            status(Some([true, true]), 1, false, None),
+            Status::Unverified
+        );
+        assert_eq!(
+            status(Some([true, true]), 1, true, Some(false)),
+            Status::Wrong
+        );
+        assert_eq!(
+            status(Some([true, true]), 1, true, Some(true)),
+            Status::Verified
+        );
+        // other years are not run
+        assert_eq!(status(Some([true, true]), 2, true, None), Status::Verified);
+    }
+
+    #[test]
+    fn test_is_hello_world() {
+        assert!(is_hello_world(
+            "fn main() {\n    println!(\"Hello, world!\");\n}\n"
+        ));
+        assert!(!is_hello_world(
+            "fn main() {\n    let x = 1;\n    println!(\"{x}\");\n}\n"
+        ));
+    }
+}
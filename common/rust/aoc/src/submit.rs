@@ -0,0 +1,495 @@
+//! The `submit` command: sends the answer of a part to the website.
+//!
+//! The response page is reduced to a [`Verdict`]. Every verdict is appended
+//! to the log of the year, `<year>/submissions.txt` in the
+//! [`input_dir`](crate::input::input_dir), one `<time> <day> <part> <verdict>
+//! <answer>` per line:
+//!
+//! ```text
+//! # time day part verdict answer
+//! 1670220000 5 1 too-high 9000
+//! 1670220010 5 1 wait-50 8000
+//! 1670220120 5 1 correct 7000
+//! ```
+//!
+//! An answer is only sent if the log doesn't rule it out: it was not sent
+//! before, lies between the answers that were too low and too high, the part
+//! is not solved yet and no wait is pending. A correct answer is recorded in
+//! the [`answers`](crate::answers) of the workspace for `verify`.
+
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    answers::{self, Answers},
+    fetch, input,
+    verify::describe,
+    Answer, Outcome, Workspace,
+};
+
+/// File name of the log, relative to the year in the input directory.
+pub const LOG_FILE_NAME: &str = "submissions.txt";
+
+/// What the website made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint.
+    Wrong,
+    /// The answer was not checked, the seconds to wait before the next one.
+    Wait(u64),
+    /// The part was solved before, the answer was not checked.
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(seconds) => write!(f, "wait-{seconds}"),
+            Verdict::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "already-solved" => Ok(Verdict::AlreadySolved),
+            _ => s
+                .strip_prefix("wait-")
+                .and_then(|seconds| seconds.parse().ok())
+                .map(Verdict::Wait)
+                .ok_or_else(|| format!("invalid verdict `{s}`")),
+        }
+    }
+}
+
+/// Reads the verdict from the page the website responds with.
+pub fn parse_response(page: &str) -> Result<Verdict, String> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("That's not the right answer") {
+        Ok(if page.contains("too high") {
+            Verdict::TooHigh
+        } else if page.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if page.contains("You gave an answer too recently") {
+        wait_time(page).map(Verdict::Wait)
+    } else if page.contains("Did you already complete it?") {
+        Ok(Verdict::AlreadySolved)
+    } else {
+        Err("unexpected response to the answer".into())
+    }
+}
+
+/// The seconds of `You have 1m 5s left to wait.`
+fn wait_time(page: &str) -> Result<u64, String> {
+    let end = page
+        .find(" left to wait")
+        .ok_or("the response doesn't say how long to wait")?;
+    let start = page[..end].rfind("have ").map_or(0, |at| at + 5);
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let unit = match amount.chars().last() {
+                Some('h') => 3600,
+                Some('m') => 60,
+                Some('s') => 1,
+                _ => return None,
+            };
+            Some(unit * amount[..amount.len() - 1].parse::<u64>().ok()?)
+        })
+        .sum::<Option<u64>>()
+        .ok_or_else(|| format!("invalid wait time `{}`", &page[start..end]))
+}
+
+/// An answer to submit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+}
+
+/// A line of the log.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    /// Unix time of the submission in seconds.
+    time: u64,
+    day: u8,
+    part: u8,
+    verdict: Verdict,
+    answer: Answer,
+}
+
+fn parse_log(content: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = vec![];
+    for (line_no, line) in content.lines().enumerate().map(|(i, l)| (i + 1, l.trim())) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.splitn(5, char::is_whitespace).collect();
+        let &[time, day, part, verdict, answer] = fields.as_slice() else {
+            return Err(format!(
+                "{line_no}: expected `<time> <day> <part> <verdict> <answer>`"
+            ));
+        };
+        let invalid = |what: &str, value: &str| format!("{line_no}: invalid {what} `{value}`");
+        entries.push(Entry {
+            time: time.parse().map_err(|_| invalid("time", time))?,
+            day: day.parse().map_err(|_| invalid("day", day))?,
+            part: part.parse().map_err(|_| invalid("part", part))?,
+            verdict: verdict.parse().map_err(|err| format!("{line_no}: {err}"))?,
+            answer: answer.into(),
+        });
+    }
+    Ok(entries)
+}
+
+/// Why the log rules out sending `submission` at unix time `now`.
+fn refusal(log: &[Entry], submission: &Submission, now: u64) -> Option<String> {
+    if let Some(until) = log
+        .iter()
+        .filter_map(|e| match e.verdict {
+            Verdict::Wait(seconds) => Some(e.time + seconds),
+            _ => None,
+        })
+        .max()
+        .filter(|&until| until > now)
+    {
+        return Some(format!("wait {}s before submitting again", until - now));
+    }
+    let part = log
+        .iter()
+        .filter(|e| e.day == submission.day && e.part == submission.part);
+    let answer = &submission.answer;
+    for entry in part {
+        match (entry.verdict, answer, &entry.answer) {
+            (Verdict::Correct, _, correct) => {
+                return Some(format!("the part is solved already, with {correct}"));
+            }
+            (Verdict::AlreadySolved, _, _) => return Some("the part is solved already".into()),
+            (Verdict::Wait(_), _, _) => {}
+            (verdict, _, sent) if sent == answer => {
+                return Some(format!("{answer} was submitted before, it is {verdict}"));
+            }
+            (Verdict::TooHigh, Answer::Number(n), Answer::Number(high)) if n >= high => {
+                return Some(format!(
+                    "{answer} is not lower than {high}, which is too high"
+                ));
+            }
+            (Verdict::TooLow, Answer::Number(n), Answer::Number(low)) if n <= low => {
+                return Some(format!(
+                    "{answer} is not higher than {low}, which is too low"
+                ));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Sends `submission` to `base_url` unless the log at `log_path` rules it
+/// out, and appends the verdict to the log.
+pub fn submit_to(
+    log_path: &Path,
+    base_url: &str,
+    session: impl FnOnce() -> Result<String, String>,
+    submission: &Submission,
+    now: u64,
+) -> Result<Verdict, String> {
+    let log = match fs::read_to_string(log_path) {
+        Ok(content) => {
+            parse_log(&content).map_err(|err| format!("{}:{err}", log_path.display()))?
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+        Err(err) => return Err(format!("failed to read {}: {err}", log_path.display())),
+    };
+    if let Some(reason) = refusal(&log, submission, now) {
+        return Err(format!("not submitted: {reason}"));
+    }
+    let page = post(base_url, &session()?, submission)?;
+    let verdict = parse_response(&page)?;
+    append(
+        log_path,
+        log.is_empty(),
+        &Entry {
+            time: now,
+            day: submission.day,
+            part: submission.part,
+            verdict,
+            answer: submission.answer.clone(),
+        },
+    )?;
+    Ok(verdict)
+}
+
+fn post(base_url: &str, session: &str, submission: &Submission) -> Result<String, String> {
+    let Submission {
+        year, day, part, ..
+    } = submission;
+    let url = format!("{}/{year}/day/{day}/answer", base_url.trim_end_matches('/'));
+    let response = ureq::post(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", fetch::USER_AGENT)
+        .send_form(&[
+            ("level", &part.to_string()),
+            ("answer", &submission.answer.to_string()),
+        ]);
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| format!("failed to read the response of {url}: {err}")),
+        Err(ureq::Error::Status(404, _)) => Err(format!(
+            "the puzzle of {year} day {day} is not available yet"
+        )),
+        Err(ureq::Error::Status(400 | 500, _)) => Err(format!("{url} rejected the session cookie")),
+        Err(ureq::Error::Status(status, _)) => Err(format!("{url} responded with {status}")),
+        Err(err) => Err(format!("failed to request {url}: {err}")),
+    }
+}
+
+fn append(path: &Path, new: bool, entry: &Entry) -> Result<(), String> {
+    let write = || {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if new {
+            writeln!(file, "# time day part verdict answer")?;
+        }
+        let Entry {
+            time,
+            day,
+            part,
+            verdict,
+            answer,
+        } = entry;
+        writeln!(file, "{time} {day} {part} {verdict} {answer}")
+    };
+    write().map_err(|err: io::Error| format!("failed to write {}: {err}", path.display()))
+}
+
+/// Path of the log of `year` in the `dir` of inputs.
+pub fn log_path(dir: &Path, year: u16) -> PathBuf {
+    dir.join(year.to_string()).join(LOG_FILE_NAME)
+}
+
+/// Submits `answer` or the answer computed for `part` of `day`.
+///
+/// Returns `true` if the part is solved.
+pub fn submit(
+    workspace: &Workspace,
+    day: u8,
+    part: u8,
+    answer: Option<&str>,
+) -> Result<bool, String> {
+    let answer = match answer {
+        Some(answer) => answer.into(),
+        None => solve(workspace, day, part)?,
+    };
+    let submission = Submission {
+        year: workspace.year,
+        day,
+        part,
+        answer,
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let log = log_path(&input::input_dir(), workspace.year);
+    let verdict = submit_to(&log, &fetch::base_url(), fetch::session, &submission, now)?;
+    let answer = &submission.answer;
+    match verdict {
+        Verdict::Correct => {
+            println!("day {day} part {part}: {answer} is correct");
+            record(workspace, &submission)?;
+        }
+        Verdict::TooHigh => println!("day {day} part {part}: {answer} is too high"),
+        Verdict::TooLow => println!("day {day} part {part}: {answer} is too low"),
+        Verdict::Wrong => println!("day {day} part {part}: {answer} is wrong"),
+        Verdict::Wait(seconds) => {
+            println!("day {day} part {part}: not checked, wait {seconds}s before submitting again")
+        }
+        Verdict::AlreadySolved => println!("day {day} part {part}: solved already"),
+    }
+    Ok(matches!(verdict, Verdict::Correct | Verdict::AlreadySolved))
+}
+
+/// Runs `part` of `day` on its input.
+fn solve(workspace: &Workspace, day: u8, part: u8) -> Result<Answer, String> {
+    let solution = workspace
+        .solutions
+        .iter()
+        .find(|s| s.day == day)
+        .ok_or_else(|| format!("day {day} is not implemented"))?;
+    let input = input::load(solution, None).map_err(|err| err.to_string())?;
+    let outcome = match solution.solve(&input, &[part]).parts {
+        Ok(mut parts) => parts.remove(0).outcome,
+        Err(outcome) => return Err(format!("day {day} input {}", describe(&outcome))),
+    };
+    match outcome {
+        Outcome::Solved(answer) => Ok(answer),
+        outcome => Err(format!("day {day} part {part} {}", describe(&outcome))),
+    }
+}
+
+/// Adds a correct answer to the answers of the workspace.
+fn record(workspace: &Workspace, submission: &Submission) -> Result<(), String> {
+    let path = Path::new(workspace.root).join(answers::FILE_NAME);
+    if Answers::load(&path)?
+        .get(submission.day, submission.part)
+        .is_some()
+    {
+        return Ok(());
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|err| format!("failed to open {}: {err}", path.display()))?;
+    writeln!(
+        file,
+        "{} {} {}",
+        submission.day, submission.part, submission.answer
+    )
+    .map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{log_path, parse_response, submit_to, Submission, Verdict};
+    use crate::fetch::tests::serve;
+
+    #[test]
+    fn test_parse_response() {
+        let page = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Ok(Verdict::TooHigh)
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Ok(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer. If you're stuck, ...")),
+            Ok(Verdict::Wrong)
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again. You have 1m 5s left to wait."
+            )),
+            Ok(Verdict::Wait(65))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Ok(Verdict::AlreadySolved)
+        );
+        assert!(parse_response("<html>Puzzle inputs differ by user.</html>").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let log = log_path(&dir, 2022);
+        let (url, requests) = serve(vec![
+            (200, "That's not the right answer; your answer is too high."),
+            (
+                200,
+                "You gave an answer too recently. You have 30s left to wait.",
+            ),
+            (200, "That's the right answer!"),
+        ]);
+        let session = || Ok("abc".to_owned());
+        let submission = |answer: &str| Submission {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: answer.into(),
+        };
+
+        assert_eq!(
+            submit_to(&log, &url, session, &submission("9000"), 100),
+            Ok(Verdict::TooHigh)
+        );
+        // ruled out by the log without asking the server or for the session
+        let no_session = || Err("no session".to_owned());
+        assert_eq!(
+            submit_to(&log, &url, no_session, &submission("9000"), 200),
+            Err("not submitted: 9000 was submitted before, it is too-high".into())
+        );
+        assert_eq!(
+            submit_to(&log, &url, no_session, &submission("9500"), 200),
+            Err("not submitted: 9500 is not lower than 9000, which is too high".into())
+        );
+        assert_eq!(
+            submit_to(&log, &url, session, &submission("8000"), 200),
+            Ok(Verdict::Wait(30))
+        );
+        assert_eq!(
+            submit_to(&log, &url, no_session, &submission("8000"), 220),
+            Err("not submitted: wait 10s before submitting again".into())
+        );
+        assert_eq!(
+            submit_to(&log, &url, session, &submission("8000"), 230),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(
+            submit_to(&log, &url, no_session, &submission("7000"), 300),
+            Err("not submitted: the part is solved already, with 8000".into())
+        );
+
+        assert_eq!(
+            fs::read_to_string(&log).unwrap(),
+            "# time day part verdict answer\n\
+             100 5 1 too-high 9000\n\
+             200 5 1 wait-30 8000\n\
+             230 5 1 correct 8000\n"
+        );
+        assert_eq!(
+            requests.lock().unwrap()[0],
+            "POST /2022/day/5/answer HTTP/1.1\nCookie: session=abc\nlevel=1&answer=9000"
+        );
+        assert_eq!(requests.lock().unwrap().len(), 3);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
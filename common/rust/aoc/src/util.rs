@@ -0,0 +1,175 @@
+//! Stable replacements for the nightly iterator and slice helpers the
+//! solutions used to depend on.
+//!
+//! ```
+//! use aoc::util::{partition_dedup, IterExt};
+//!
+//! let mut chunks = "[A] [B] [C]".chars().arrays::<4>();
+//! assert_eq!(chunks.next(), Some(['[', 'A', ']', ' ']));
+//! assert_eq!(chunks.nth(1), None);
+//! assert_eq!(chunks.into_remainder().collect::<String>(), "[C]");
+//!
+//! let runs: Vec<_> = [1, 1, 2, 3, 3].into_iter().group_by(|n| n % 2).collect();
+//! assert_eq!(runs, [(1, vec![1, 1]), (0, vec![2]), (1, vec![3, 3])]);
+//!
+//! let mut values = [1, 1, 2, 3, 3, 3];
+//! let (unique, duplicates) = partition_dedup(&mut values);
+//! assert_eq!((unique.len(), duplicates.len()), (3, 3));
+//! ```
+
+use std::{collections::LinkedList, iter::Peekable, vec};
+
+/// Extension methods for all iterators.
+pub trait IterExt: Iterator + Sized {
+    /// Yields `N` items at a time, see [`ArrayChunks::into_remainder`] for
+    /// the items left at the end. Named so it does not clash with the
+    /// unstable `Iterator::array_chunks`.
+    fn arrays<const N: usize>(self) -> ArrayChunks<Self, N> {
+        assert!(N > 0, "chunk size must be non-zero");
+        ArrayChunks {
+            iter: self,
+            remainder: vec![],
+        }
+    }
+
+    /// Groups consecutive items with the same key.
+    fn group_by<K: PartialEq, F: FnMut(&Self::Item) -> K>(self, key: F) -> GroupBy<Self, F> {
+        GroupBy {
+            iter: self.peekable(),
+            key,
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+/// Iterator of [`IterExt::arrays`].
+#[derive(Debug, Clone)]
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Vec<I::Item>,
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, N> {
+    /// The fewer than `N` items left after the last complete chunk, empty
+    /// unless the iterator has been exhausted.
+    pub fn into_remainder(self) -> vec::IntoIter<I::Item> {
+        self.remainder.into_iter()
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::with_capacity(N);
+        chunk.extend(self.iter.by_ref().take(N));
+        match <[I::Item; N]>::try_from(chunk) {
+            Ok(chunk) => Some(chunk),
+            Err(rest) => {
+                // keep the remainder when polled again after the end
+                if self.remainder.is_empty() {
+                    self.remainder = rest;
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Iterator of [`IterExt::group_by`], yields each key with its run of items.
+pub struct GroupBy<I: Iterator, F> {
+    iter: Peekable<I>,
+    key: F,
+}
+
+impl<I, K, F> Iterator for GroupBy<I, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let key = (self.key)(&first);
+        let mut group = vec![first];
+        while let Some(item) = self.iter.next_if(|item| (self.key)(item) == key) {
+            group.push(item);
+        }
+        Some((key, group))
+    }
+}
+
+/// Moves consecutive repeated elements to the end of `slice` and returns the
+/// deduplicated front and the repeated rest, in no particular order.
+pub fn partition_dedup<T: PartialEq>(slice: &mut [T]) -> (&mut [T], &mut [T]) {
+    let mut unique = usize::from(!slice.is_empty());
+    for read in 1..slice.len() {
+        if slice[read] != slice[unique - 1] {
+            slice.swap(read, unique);
+            unique += 1;
+        }
+    }
+    slice.split_at_mut(unique)
+}
+
+/// Removes the element at `index` of a linked list, `None` if it is too short.
+pub fn remove_at<T>(list: &mut LinkedList<T>, index: usize) -> Option<T> {
+    if index >= list.len() {
+        return None;
+    }
+    let mut tail = list.split_off(index);
+    let removed = tail.pop_front();
+    list.append(&mut tail);
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::LinkedList;
+
+    use super::{partition_dedup, remove_at, IterExt};
+
+    #[test]
+    fn test_array_chunks() {
+        let mut chunks = (0..7).arrays::<3>();
+        assert_eq!(chunks.by_ref().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(chunks.into_remainder().collect::<Vec<_>>(), [6]);
+        let mut exact = (0..4).arrays::<2>();
+        assert_eq!(exact.by_ref().count(), 2);
+        assert_eq!(exact.into_remainder().len(), 0);
+    }
+
+    #[test]
+    fn test_group_by() {
+        let words = ["apple", "avocado", "banana", "cherry", "cranberry"];
+        let groups: Vec<_> = words
+            .iter()
+            .group_by(|w| w.as_bytes()[0])
+            .map(|(k, group)| (k as char, group.len()))
+            .collect();
+        assert_eq!(groups, [('a', 2), ('b', 1), ('c', 2)]);
+        assert_eq!(std::iter::empty::<u8>().group_by(|&b| b).next(), None);
+    }
+
+    #[test]
+    fn test_partition_dedup() {
+        let mut values = [1, 2, 2, 3, 3, 3, 1];
+        let (unique, duplicates) = partition_dedup(&mut values);
+        assert_eq!(unique, [1, 2, 3, 1]);
+        duplicates.sort();
+        assert_eq!(duplicates, [2, 3, 3]);
+        let (unique, duplicates) = partition_dedup::<u8>(&mut []);
+        assert!(unique.is_empty() && duplicates.is_empty());
+    }
+
+    #[test]
+    fn test_remove_at() {
+        let mut list = LinkedList::from([1, 2, 3, 4]);
+        assert_eq!(remove_at(&mut list, 1), Some(2));
+        assert_eq!(remove_at(&mut list, 3), None);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 3, 4]);
+    }
+}
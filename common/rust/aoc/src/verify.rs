@@ -0,0 +1,188 @@
+//! The `verify` command: checks all solutions against the recorded answers.
+
+use std::{path::Path, time::Duration};
+
+use crate::{
+    answers::{self, Answers},
+    input,
+    memory::{self, Bytes, Usage},
+    Answer, Outcome, Workspace,
+};
+
+/// Result of checking a single part.
+#[derive(Debug, PartialEq)]
+enum Status {
+    Pass,
+    /// The part did not produce the recorded answer.
+    Fail(String),
+    /// There is no recorded answer to check against.
+    Missing,
+}
+
+struct Row {
+    day: u8,
+    part: u8,
+    status: Status,
+    parse: Option<Duration>,
+    elapsed: Option<Duration>,
+    memory: Option<Usage>,
+}
+
+/// Runs every part with a recorded answer and prints a table of the results.
+///
+/// Parts without a recorded answer are reported as missing but not run, so
+/// unfinished days can't stall the verification. Returns `false` if any
+/// part failed.
+pub fn verify(workspace: &Workspace, day: Option<u8>) -> Result<bool, String> {
+    let answers = Answers::load(&Path::new(workspace.root).join(answers::FILE_NAME))?;
+    let mut rows = vec![];
+    for solution in workspace.solutions {
+        if day.is_some_and(|d| d != solution.day) {
+            continue;
+        }
+        let (recorded, missing): (Vec<u8>, Vec<u8>) = [1, 2]
+            .into_iter()
+            .partition(|&part| answers.get(solution.day, part).is_some());
+        rows.extend(missing.into_iter().map(|part| Row {
+            day: solution.day,
+            part,
+            status: Status::Missing,
+            parse: None,
+            elapsed: None,
+            memory: None,
+        }));
+        if recorded.is_empty() {
+            continue;
+        }
+        let input = match input::load(solution, None) {
+            Ok(input) => input,
+            Err(err) => {
+                rows.extend(recorded.into_iter().map(|part| Row {
+                    day: solution.day,
+                    part,
+                    status: Status::Fail(err.to_string()),
+                    parse: None,
+                    elapsed: None,
+                    memory: None,
+                }));
+                continue;
+            }
+        };
+        let run = solution.solve(&input, &recorded);
+        match run.parts {
+            Ok(parts) => rows.extend(parts.into_iter().map(|part| {
+                Row {
+                    day: solution.day,
+                    part: part.part,
+                    status: check(
+                        &part.outcome,
+                        answers
+                            .get(solution.day, part.part)
+                            .expect("recorded answer"),
+                    ),
+                    parse: Some(run.parse),
+                    elapsed: Some(part.elapsed),
+                    memory: part.memory,
+                }
+            })),
+            Err(outcome) => rows.extend(recorded.into_iter().map(|part| Row {
+                day: solution.day,
+                part,
+                status: Status::Fail(format!("input {}", describe(&outcome))),
+                parse: Some(run.parse),
+                elapsed: None,
+                memory: run.parse_memory,
+            })),
+        }
+    }
+    rows.sort_by_key(|row| (row.day, row.part));
+    print_table(&rows);
+    Ok(!rows.iter().any(|row| matches!(row.status, Status::Fail(_))))
+}
+
+fn check(outcome: &Outcome, expected: &Answer) -> Status {
+    match outcome {
+        Outcome::Solved(answer) if answer == expected => Status::Pass,
+        Outcome::Solved(answer) => Status::Fail(format!("got {answer}, expected {expected}")),
+        outcome => Status::Fail(describe(outcome)),
+    }
+}
+
+pub(crate) fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Solved(answer) => answer.to_string(),
+        Outcome::Unimplemented => "not implemented".into(),
+        Outcome::Failed(err) => format!("failed: {err}"),
+        Outcome::Panicked(msg) => format!("panicked: {msg}"),
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let time = |d: Option<Duration>| d.map_or_else(|| "-".into(), |d| format!("{d:.1?}"));
+    // The heap usage is only known with the counting allocator.
+    let usage = |u: Option<Usage>| match (memory::enabled(), u) {
+        (false, _) => String::new(),
+        (true, None) => format!(" {:>10} {:>8}", "-", "-"),
+        (true, Some(u)) => format!(" {:>10} {:>8}", Bytes(u.peak).to_string(), u.allocations),
+    };
+    println!(
+        "{:>3} {:>4}  {:<7} {:>10} {:>10}{}  details",
+        "day",
+        "part",
+        "status",
+        "parse",
+        "time",
+        if memory::enabled() {
+            format!(" {:>10} {:>8}", "peak", "allocs")
+        } else {
+            String::new()
+        }
+    );
+    for row in rows {
+        let (status, details) = match &row.status {
+            Status::Pass => ("pass", ""),
+            Status::Fail(details) => ("FAIL", details.as_str()),
+            Status::Missing => ("missing", "no recorded answer"),
+        };
+        let line = format!(
+            "{:>3} {:>4}  {:<7} {:>10} {:>10}{}  {details}",
+            row.day,
+            row.part,
+            status,
+            time(row.parse),
+            time(row.elapsed),
+            usage(row.memory)
+        );
+        println!("{}", line.trim_end());
+    }
+    let count = |f: fn(&Status) -> bool| rows.iter().filter(|r| f(&r.status)).count();
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(s, Status::Fail(_))),
+        count(|s| *s == Status::Missing)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, Status};
+    use crate::{Answer, Outcome};
+
+    #[test]
+    fn test_check() {
+        let expected = Answer::Number(24);
+        assert_eq!(
+            check(&Outcome::Solved(24_u8.into()), &expected),
+            Status::Pass
+        );
+        assert_eq!(
+            check(&Outcome::Solved(25_u8.into()), &expected),
+            Status::Fail("got 25, expected 24".into())
+        );
+        assert_eq!(
+            check(&Outcome::Unimplemented, &expected),
+            Status::Fail("not implemented".into())
+        );
+    }
+}
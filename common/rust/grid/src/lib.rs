@@ -0,0 +1,464 @@
+//! A rectangular grid of cells as found in many puzzles of the Advent of Code.
+//!
+//! Grids are usually parsed from a character map, every byte becomes a cell
+//! through its `TryFrom<u8>` implementation:
+//!
+//! ```
+//! use grid::{Grid, Pos};
+//!
+//! let grid: Grid<char> = "#.#\n..#\n".parse().unwrap();
+//! assert_eq!(grid[Pos::new(1, 2)], '#');
+//! assert_eq!(grid.neighbours4(Pos::new(0, 0)).count(), 2);
+//! assert_eq!(grid.to_string(), "#.#\n..#\n");
+//! ```
+
+mod pos;
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+pub use pos::{Direction, Pos};
+
+/// Cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid {
+            cells: vec![],
+            width: 0,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid of `height` rows of `width` copies of `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+        }
+    }
+
+    /// A grid of the row by row `cells`, `None` unless they fill complete
+    /// rows of `width` cells.
+    pub fn from_vec(cells: Vec<T>, width: usize) -> Option<Self> {
+        // Only an empty grid can have a width of zero.
+        cells
+            .len()
+            .is_multiple_of(width)
+            .then_some(Grid { cells, width })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height() && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn swap(&mut self, a: Pos, b: Pos) {
+        let (a, b) = (self.index_of(a), self.index_of(b));
+        self.cells.swap(a, b);
+    }
+
+    /// The adjacent position in `dir`, `None` if it is outside of the grid.
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        pos.step(dir).filter(|&p| self.contains(p))
+    }
+
+    /// The adjacent positions in the four directions inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The adjacent positions including the diagonals inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        const DELTAS: [(isize, isize); 8] = [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ];
+        DELTAS
+            .into_iter()
+            .filter_map(move |(d_row, d_col)| pos.offset(d_row, d_col))
+            .filter(|&p| self.contains(p))
+    }
+
+    /// The positions from `pos` (exclusive) in `dir` up to the edge.
+    pub fn ray(&self, pos: Pos, dir: Direction) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, dir), move |&p| self.step(p, dir))
+    }
+
+    /// All positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Pos::new(i / width, i % width))
+    }
+
+    /// All cells with their position row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// All cells row by row.
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` refuses a width of zero, an empty grid has no rows.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The position of the first cell matching `predicate` row by row.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(predicate)?;
+        Some(Pos::new(i / self.width, i % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+        }
+    }
+
+    /// Mirrors the grid at its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height(), |p| Pos::new(p.col, p.row))
+    }
+
+    /// Rotates the grid by 90° clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height();
+        self.remap(height, |p| Pos::new(height - 1 - p.col, p.row))
+    }
+
+    /// Rotates the grid by 90° counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height(), |p| Pos::new(p.col, self.width - 1 - p.row))
+    }
+
+    /// A grid of the given width with the cell of `source(pos)` at `pos`.
+    fn remap(&self, width: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.cells.len().checked_div(width).unwrap_or(0);
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| Pos::new(row, col)))
+            .map(|p| self[source(p)].clone())
+            .collect();
+        Grid { cells, width }
+    }
+
+    fn index_of(&self, pos: Pos) -> usize {
+        assert!(
+            self.contains(pos),
+            "position {pos:?} is outside of the {}x{} grid",
+            self.width,
+            self.height()
+        );
+        pos.row * self.width + pos.col
+    }
+}
+
+impl<T: TryFrom<u8>> Grid<T> {
+    /// Parses a character map whose lines may be shorter than the longest
+    /// one, the missing cells are parsed from `pad`.
+    pub fn parse_padded(s: &str, pad: u8) -> Result<Self, ParseGridError> {
+        Self::parse_lines(s, Some(pad))
+    }
+
+    /// Empty lines are skipped, so the map may be surrounded by blank lines.
+    fn parse_lines(s: &str, pad: Option<u8>) -> Result<Self, ParseGridError> {
+        let lines: Vec<(usize, &str)> = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line))
+            .filter(|(_, line)| !line.is_empty())
+            .collect();
+        let width = match pad {
+            Some(_) => lines.iter().map(|(_, line)| line.len()).max(),
+            None => lines.first().map(|(_, line)| line.len()),
+        }
+        .unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (line_no, line) in lines {
+            if pad.is_none() && line.len() != width {
+                return Err(ParseGridError::Ragged {
+                    line: line_no,
+                    expected: width,
+                    found: line.len(),
+                });
+            }
+            for (column, byte) in line.bytes().enumerate() {
+                let cell = T::try_from(byte).map_err(|_| ParseGridError::InvalidCell {
+                    line: line_no,
+                    column: column + 1,
+                    byte,
+                })?;
+                cells.push(cell);
+            }
+            if let Some(pad) = pad {
+                for column in line.len()..width {
+                    let cell = T::try_from(pad).map_err(|_| ParseGridError::InvalidCell {
+                        line: line_no,
+                        column: column + 1,
+                        byte: pad,
+                    })?;
+                    cells.push(cell);
+                }
+            }
+        }
+        Ok(Grid { cells, width })
+    }
+}
+
+impl<T: TryFrom<u8>> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    /// Parses a rectangular character map, see [`Grid::parse_padded`] for
+    /// maps with lines of different lengths.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_lines(s, None)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `pos` is outside of the grid, see [`Grid::get`].
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let i = self.index_of(pos);
+        &mut self.cells[i]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Writes every row on a line of its own.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A character map could not be parsed into a [`Grid`], lines and columns
+/// count from one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    InvalidCell {
+        line: usize,
+        column: usize,
+        byte: u8,
+    },
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::InvalidCell { line, column, byte } => write!(
+                f,
+                "line {line}, column {column}: invalid cell `{}`",
+                byte.escape_ascii()
+            ),
+            ParseGridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line}: expected {expected} cells, found {found}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Grid, ParseGridError, Pos};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tile {
+        Wall,
+        Open,
+        Void,
+    }
+
+    impl TryFrom<u8> for Tile {
+        type Error = ();
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                b'#' => Ok(Tile::Wall),
+                b'.' => Ok(Tile::Open),
+                b' ' => Ok(Tile::Void),
+                _ => Err(()),
+            }
+        }
+    }
+
+    fn grid() -> Grid<char> {
+        "\nabc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(1, 0)], 'd');
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 3)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Pos::new(1, 1)));
+
+        let tiles: Grid<Tile> = "#.\n.#".parse().unwrap();
+        assert_eq!(tiles[Pos::new(1, 1)], Tile::Wall);
+        assert_eq!("".parse::<Grid<Tile>>(), Ok(Grid::default()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "#.\n.x".parse::<Grid<Tile>>(),
+            Err(ParseGridError::InvalidCell {
+                line: 2,
+                column: 2,
+                byte: b'x'
+            })
+        );
+        let err = "#.\n\n.#.".parse::<Grid<Tile>>().unwrap_err();
+        assert_eq!(err.to_string(), "line 3: expected 2 cells, found 3");
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::<Tile>::parse_padded("  #\n.#.#\n", b' ').unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!(grid[Pos::new(0, 0)], Tile::Void);
+        assert_eq!(grid[Pos::new(0, 3)], Tile::Void);
+        assert_eq!(grid[Pos::new(1, 3)], Tile::Wall);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let corner = Pos::new(0, 0);
+        assert_eq!(
+            grid.neighbours4(corner).collect::<Vec<_>>(),
+            [Pos::new(0, 1), Pos::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Pos::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::new(4, 2, 0);
+        assert_eq!(
+            grid.ray(Pos::new(1, 1), Direction::Right)
+                .collect::<Vec<_>>(),
+            [Pos::new(1, 2), Pos::new(1, 3)]
+        );
+        assert_eq!(grid.ray(Pos::new(1, 1), Direction::Down).count(), 0);
+    }
+
+    #[test]
+    fn test_rotate_and_transpose() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_left().rotate_right(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut grid = grid();
+        grid[Pos::new(0, 0)] = 'x';
+        grid.swap(Pos::new(0, 0), Pos::new(1, 2));
+        *grid.get_mut(Pos::new(0, 1)).unwrap() = '.';
+        assert_eq!(grid.to_string(), "f.c\ndex\n");
+        assert_eq!(grid.get_mut(Pos::new(5, 5)), None);
+        assert_eq!(
+            grid.map(|c| c.is_alphabetic())
+                .values()
+                .filter(|&&b| b)
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "position Pos { row: 2, col: 0 } is outside of the 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = grid()[Pos::new(2, 0)];
+    }
+
+    #[test]
+    fn test_from_vec() {
+        assert!(Grid::from_vec(vec![1, 2, 3], 2).is_none());
+        let grid = Grid::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2], [3, 4]]);
+        assert_eq!(grid.to_string(), "12\n34\n");
+    }
+}
@@ -0,0 +1,121 @@
+//! Positions and directions on a [`Grid`](crate::Grid).
+
+/// A position on a grid, rows grow downwards and columns to the right.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    /// Moves by the given number of rows and columns, `None` if that would
+    /// leave the non-negative positions.
+    pub fn offset(self, d_row: isize, d_col: isize) -> Option<Self> {
+        Some(Pos {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+
+    /// The adjacent position in `dir`, see [`Pos::offset`].
+    pub fn step(self, dir: Direction) -> Option<Self> {
+        let (d_row, d_col) = dir.delta();
+        self.offset(d_row, d_col)
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    /// Converts a `(row, col)` pair.
+    fn from((row, col): (usize, usize)) -> Self {
+        Pos { row, col }
+    }
+}
+
+/// One of the four directions along the rows and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions clockwise, starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The change of row and column of a step in this direction.
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub const fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Pos};
+
+    #[test]
+    fn test_step() {
+        let pos = Pos::new(0, 3);
+        assert_eq!(pos.step(Direction::Up), None);
+        assert_eq!(pos.step(Direction::Right), Some(Pos::new(0, 4)));
+        assert_eq!(pos.step(Direction::Down), Some(Pos::new(1, 3)));
+        assert_eq!(pos.offset(2, -3), Some(Pos::new(2, 0)));
+        assert_eq!(pos.offset(0, -4), None);
+        assert_eq!(pos.manhattan(Pos::new(4, 1)), 6);
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+        }
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    }
+}
@@ -0,0 +1,294 @@
+//! Sets of integers stored as sorted, disjoint intervals.
+//!
+//! Ranges are accepted inclusive (`a..=b`) as well as exclusive (`a..b`) and
+//! stored half-open, so the set operations never have to visit single values:
+//!
+//! ```
+//! use intervals::IntervalSet;
+//!
+//! let mut set: IntervalSet<i32> = [1..=3, 7..=9].into_iter().collect();
+//! set.insert(4..6);
+//! assert_eq!(set.coverage(), 8);
+//! assert_eq!(set.gaps().next(), Some(6..7));
+//! ```
+
+use std::{
+    fmt,
+    ops::{Add, Bound, Range, RangeBounds, RangeInclusive, Sub},
+};
+
+/// The integer types an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Converts bounded `range` to a half-open range.
+///
+/// Panics if the range is unbounded, as there is no infinity to store.
+fn half_open<T: Integer>(range: impl RangeBounds<T>) -> Range<T> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + T::ONE,
+        Bound::Unbounded => panic!("interval without a start"),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + T::ONE,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => panic!("interval without an end"),
+    };
+    start..end
+}
+
+/// A set of integers, stored as the smallest list of half-open ranges.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Sorted, non-empty and neither overlapping nor touching.
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+
+    /// Adds all values of `range`, empty ranges are ignored.
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        let range = half_open(range);
+        if range.is_empty() {
+            return;
+        }
+        // the ranges before and after the inserted one stay untouched
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first == last {
+            range
+        } else {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes all values of `range`.
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        *self = self.difference(&IntervalSet::from(half_open(range)));
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn coverage(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |sum, r| sum + (r.end - r.start))
+    }
+
+    /// The smallest and largest value, if any.
+    pub fn bounds(&self) -> Option<(T, T)> {
+        Some((self.ranges.first()?.start, self.ranges.last()?.end - T::ONE))
+    }
+
+    /// The disjoint half-open ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /// The missing ranges between the smallest and the largest value.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.windows(2).map(|w| w[0].end..w[1].start)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let overlap = x.start.max(y.start)..x.end.min(y.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // the range ending first can't overlap any further range
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The values of `self` which are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = others.peek() {
+                if cut.end <= start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > range.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Whether every value of `other` is in `self`.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    /// Whether the sets have a value in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Integer> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Integer, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+impl<T: Integer> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|range| self.insert(range));
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.ranges).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalSet;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().map(|&(a, b)| a..=b).collect()
+    }
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|r| (r.start, r.end - 1)).collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut s = set(&[(1, 3), (10, 12)]);
+        s.insert(5..=6);
+        assert_eq!(ranges(&s), [(1, 3), (5, 6), (10, 12)]);
+        // touching ranges are merged as well
+        s.insert(4..5);
+        assert_eq!(ranges(&s), [(1, 6), (10, 12)]);
+        s.insert(0..=20);
+        assert_eq!(ranges(&s), [(0, 20)]);
+        s.insert(7..7);
+        assert_eq!(ranges(&s), [(0, 20)]);
+        assert_eq!(ranges(&set(&[(5, 8), (-3, 0), (1, 2)])), [(-3, 2), (5, 8)]);
+    }
+
+    #[test]
+    fn test_contains_and_coverage() {
+        let s = set(&[(-2, 2), (5, 5)]);
+        assert!(s.contains(-2) && s.contains(2) && s.contains(5));
+        assert!(!s.contains(3) && !s.contains(6) && !s.contains(-3));
+        assert_eq!(s.coverage(), 6);
+        assert_eq!(s.bounds(), Some((-2, 5)));
+        assert_eq!(s.gaps().next(), Some(3..5));
+        assert_eq!(IntervalSet::<u8>::new().bounds(), None);
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        let a = set(&[(0, 4), (10, 14)]);
+        let b = set(&[(3, 11), (20, 21)]);
+        assert_eq!(ranges(&a.union(&b)), [(0, 14), (20, 21)]);
+        assert_eq!(ranges(&a.intersection(&b)), [(3, 4), (10, 11)]);
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&set(&[(5, 9)])));
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(-5, 0), (3, 4), (8, 22), (30, 40)]);
+        assert_eq!(ranges(&a.difference(&b)), [(1, 2), (5, 7), (23, 29)]);
+        assert_eq!(ranges(&b.difference(&a)), [(-5, -1), (11, 19), (31, 40)]);
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = set(&[(0, 9)]);
+        c.remove(3..=3);
+        assert_eq!(ranges(&c), [(0, 2), (4, 9)]);
+        assert!(c.is_superset(&set(&[(0, 1), (5, 9)])));
+        assert!(!c.is_superset(&set(&[(2, 4)])));
+    }
+}
@@ -0,0 +1,409 @@
+//! Shortest path searches over graphs given by a `neighbors` closure.
+//!
+//! All searches accept several start nodes, the path found starts at the
+//! closest of them. Besides the path every search reports how many nodes it
+//! visited, which helps to compare heuristics and state encodings:
+//!
+//! ```
+//! let search = search::bfs([1_u32], |&n| [n + 1, n * 2], |&n| n == 10);
+//! assert_eq!(search.path.unwrap().nodes, [1, 2, 4, 5, 10]);
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cost of an edge, `Default` has to be the cost of an empty path.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// A path from a start node to a goal, both included.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Counters of the work done by a search.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// Nodes whose neighbors have been expanded, including the goal.
+    pub visited: usize,
+    /// Nodes pushed to the frontier, with repetitions for improved costs.
+    pub queued: usize,
+}
+
+/// The result of a search, `path` is `None` if no goal is reachable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Search<N, C> {
+    pub path: Option<Path<N, C>>,
+    pub stats: Stats,
+}
+
+/// The nodes discovered by a search with the index of their parent.
+struct Tree<N> {
+    nodes: Vec<(N, Option<usize>)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Tree<N> {
+    fn new() -> Self {
+        Tree {
+            nodes: vec![],
+            index: HashMap::new(),
+        }
+    }
+
+    /// Adds `node` unless it has been discovered already.
+    fn discover(&mut self, node: N, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let i = self.nodes.len();
+                self.nodes.push((entry.key().clone(), parent));
+                entry.insert(i);
+                Some(i)
+            }
+        }
+    }
+
+    /// The nodes from the root to the node at `i`.
+    fn path(&self, mut i: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[i].0.clone()];
+        while let Some(parent) = self.nodes[i].1 {
+            path.push(self.nodes[parent].0.clone());
+            i = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth first search, the cost of a path is its number of edges.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = Tree::new();
+    let mut queue = VecDeque::new();
+    let mut stats = Stats::default();
+    for start in starts {
+        if let Some(i) = tree.discover(start, None) {
+            queue.push_back((i, 0));
+            stats.queued += 1;
+        }
+    }
+    while let Some((i, depth)) = queue.pop_front() {
+        stats.visited += 1;
+        let node = tree.nodes[i].0.clone();
+        if success(&node) {
+            return Search {
+                path: Some(Path {
+                    nodes: tree.path(i),
+                    cost: depth,
+                }),
+                stats,
+            };
+        }
+        for next in neighbors(&node) {
+            if let Some(j) = tree.discover(next, Some(i)) {
+                queue.push_back((j, depth + 1));
+                stats.queued += 1;
+            }
+        }
+    }
+    Search { path: None, stats }
+}
+
+/// Dijkstra's algorithm for non-negative edge costs.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), success)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = Tree::new();
+    // The best known cost of every discovered node.
+    let mut costs = vec![];
+    let mut heap = BinaryHeap::new();
+    let mut stats = Stats::default();
+    for start in starts {
+        if let Some(i) = tree.discover(start, None) {
+            costs.push(C::default());
+            heap.push(Reverse((heuristic(&tree.nodes[i].0), C::default(), i)));
+            stats.queued += 1;
+        }
+    }
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            // a cheaper path to the node has been found after queueing it
+            continue;
+        }
+        stats.visited += 1;
+        let node = tree.nodes[i].0.clone();
+        if success(&node) {
+            return Search {
+                path: Some(Path {
+                    nodes: tree.path(i),
+                    cost,
+                }),
+                stats,
+            };
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            let j = match tree.index.get(&next) {
+                Some(&j) if next_cost >= costs[j] => continue,
+                Some(&j) => {
+                    costs[j] = next_cost;
+                    tree.nodes[j].1 = Some(i);
+                    j
+                }
+                None => {
+                    costs.push(next_cost);
+                    tree.discover(next, Some(i)).expect("undiscovered node")
+                }
+            };
+            heap.push(Reverse((
+                next_cost + heuristic(&tree.nodes[j].0),
+                next_cost,
+                j,
+            )));
+            stats.queued += 1;
+        }
+    }
+    Search { path: None, stats }
+}
+
+/// Breadth first search from both ends at once, it visits far fewer nodes
+/// than [`bfs`] on large graphs.
+///
+/// The graph has to be undirected: `neighbors` is used in both directions.
+pub fn bidirectional_bfs<N, I>(
+    start: N,
+    goal: N,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut stats = Stats {
+        visited: 0,
+        queued: 2,
+    };
+    if start == goal {
+        stats.visited = 1;
+        return Search {
+            path: Some(Path {
+                nodes: vec![start],
+                cost: 0,
+            }),
+            stats,
+        };
+    }
+    let mut trees = [Tree::new(), Tree::new()];
+    let mut depths = [vec![0], vec![0]];
+    let mut frontiers = [vec![0], vec![0]];
+    trees[0].discover(start, None);
+    trees[1].discover(goal, None);
+    loop {
+        // expand a whole level of the smaller frontier
+        let side = usize::from(frontiers[1].len() < frontiers[0].len());
+        if frontiers[side].is_empty() {
+            return Search { path: None, stats };
+        }
+        let [forward, backward] = &mut trees;
+        let (this, other) = match side {
+            0 => (forward, &*backward),
+            _ => (backward, &*forward),
+        };
+        let mut meeting: Option<(usize, usize, usize)> = None;
+        let mut next_frontier = vec![];
+        for i in std::mem::take(&mut frontiers[side]) {
+            stats.visited += 1;
+            let node = this.nodes[i].0.clone();
+            for next in neighbors(&node) {
+                if let Some(&j) = other.index.get(&next) {
+                    let length = depths[side][i] + 1 + depths[1 - side][j];
+                    if meeting.is_none_or(|(_, _, best)| length < best) {
+                        meeting = Some((i, j, length));
+                    }
+                } else if let Some(k) = this.discover(next, Some(i)) {
+                    depths[side].push(depths[side][i] + 1);
+                    next_frontier.push(k);
+                    stats.queued += 1;
+                }
+            }
+        }
+        frontiers[side] = next_frontier;
+        if let Some((i, j, cost)) = meeting {
+            let mut nodes = this.path(i);
+            let mut rest = other.path(j);
+            rest.reverse();
+            nodes.extend(rest);
+            if side == 1 {
+                nodes.reverse();
+            }
+            return Search {
+                path: Some(Path { nodes, cost }),
+                stats,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::{Grid, Pos};
+
+    use super::{astar, bfs, bidirectional_bfs, dijkstra, Path};
+
+    const MAZE: &str = "
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#G
+";
+
+    fn maze() -> (Grid<char>, Pos, Pos) {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.position(|&c| c == 'S').unwrap();
+        let goal = grid.position(|&c| c == 'G').unwrap();
+        (grid, start, goal)
+    }
+
+    fn open(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        grid.neighbours4(pos).filter(|&p| grid[p] != '#').collect()
+    }
+
+    /// Checks that `path` is a connected path of open cells from `from` to `to`.
+    fn assert_walkable(grid: &Grid<char>, path: &[Pos], from: Pos, to: Pos) {
+        assert_eq!(path.first(), Some(&from));
+        assert_eq!(path.last(), Some(&to));
+        for step in path.windows(2) {
+            assert!(open(grid, step[0]).contains(&step[1]), "{step:?}");
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, goal) = maze();
+        let search = bfs([start], |&p| open(&grid, p), |&p| p == goal);
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_walkable(&grid, &path.nodes, start, goal);
+        assert!(search.stats.visited <= search.stats.queued);
+    }
+
+    #[test]
+    fn test_bfs_multi_source() {
+        let (grid, start, goal) = maze();
+        let other = Pos::new(0, 7);
+        let search = bfs([start, other], |&p| open(&grid, p), |&p| p == goal);
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 4);
+        assert_walkable(&grid, &path.nodes, other, goal);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let (grid, start, _) = maze();
+        let search = bfs([start], |&p| open(&grid, p), |&p| p == Pos::new(0, 3));
+        assert_eq!(search.path, None);
+        // every open cell has been visited
+        assert_eq!(
+            search.stats.visited,
+            grid.values().filter(|&&c| c != '#').count()
+        );
+        let search = dijkstra([start], |&p| [(p, 1)], |_| false);
+        assert_eq!(search.path, None::<Path<Pos, u32>>);
+        assert_eq!(search.stats.visited, 1);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // going around is cheaper than the direct edge
+        let edges = |&n: &char| match n {
+            'a' => vec![('b', 7), ('c', 2)],
+            'c' => vec![('d', 2)],
+            'd' => vec![('b', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra(['a'], edges, |&n| n == 'b');
+        assert_eq!(
+            search.path,
+            Some(Path {
+                nodes: vec!['a', 'c', 'd', 'b'],
+                cost: 5
+            })
+        );
+        assert_eq!(search.stats.visited, 4);
+    }
+
+    #[test]
+    fn test_astar() {
+        let (grid, start, goal) = maze();
+        let weighted = |&p: &Pos| open(&grid, p).into_iter().map(|p| (p, 1));
+        let path = astar([start], weighted, |p| p.manhattan(goal), |&p| p == goal);
+        let plain = dijkstra([start], weighted, |&p| p == goal);
+        assert_eq!(path.path.as_ref().unwrap().cost, 15);
+        assert_walkable(&grid, &path.path.unwrap().nodes, start, goal);
+        assert!(path.stats.visited <= plain.stats.visited);
+    }
+
+    #[test]
+    fn test_bidirectional_bfs() {
+        let (grid, start, goal) = maze();
+        let search = bidirectional_bfs(start, goal, |&p| open(&grid, p));
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 15);
+        assert_walkable(&grid, &path.nodes, start, goal);
+
+        let reverse = bidirectional_bfs(goal, start, |&p| open(&grid, p));
+        assert_eq!(reverse.path.unwrap().cost, 15);
+        assert_eq!(
+            bidirectional_bfs(start, start, |&p| open(&grid, p))
+                .path
+                .unwrap()
+                .nodes,
+            [start]
+        );
+        let wall = Pos::new(0, 3);
+        let search = bidirectional_bfs(start, wall, |&p| match grid[p] {
+            '#' => vec![],
+            _ => open(&grid, p),
+        });
+        assert_eq!(search.path, None);
+    }
+}